@@ -20,11 +20,15 @@ cli-subcmd-compile-help =
     .arg-input = Compile the program from <INPUT>
     .arg-backend = Compile the program using <BACKEND>
     .arg-output = Write the output to <OUTPUT>
+    .arg-target = Compile the program for <TARGET> machine
+    .arg-overflow = Handle integer overflow using <OVERFLOW> policy
+    .arg-no-debug-assertions = Remove assert statements from the program
 
 cli-subcmd-run-help =
     .description = Run a program using the interpreter
     .arg-input = Run the program from <INPUT>
     .arg-no-jit = Disable JIT compilation
+    .arg-overflow = Handle integer overflow using <OVERFLOW> policy
 
 cli-error =
     .unexpected-subcommand = Unexpected subcommand { $subcommand }
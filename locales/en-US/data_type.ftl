@@ -269,6 +269,114 @@ data-type-description-int64 =
         }
     }
 
+data-type-description-int128 =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No 128-bit integer
+            *[lowercase] no 128-bit integer
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] 128-bit integer
+            *[lowercase] 128-bit integer
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] A 128-bit integer {$value}
+                *[false] A 128-bit integer
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] a 128-bit integer {$value}
+                *[false] a 128-bit integer
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] 128-bit integer {$value}
+                *[false] 128-bit integer
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] 128-bit integer {$value}
+                *[false] 128-bit integer
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} 128-bit integers
+        *[false]
+            { $capitalization ->
+            [uppercase] 128-bit integers
+            *[lowercase] 128-bit integers
+            }
+        }
+    }
+
+data-type-description-bigint =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No arbitrary-precision integer
+            *[lowercase] no arbitrary-precision integer
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] Arbitrary-precision integer
+            *[lowercase] arbitrary-precision integer
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] An arbitrary-precision integer {$value}
+                *[false] An arbitrary-precision integer
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] an arbitrary-precision integer {$value}
+                *[false] an arbitrary-precision integer
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] Arbitrary-precision integer {$value}
+                *[false] Arbitrary-precision integer
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] arbitrary-precision integer {$value}
+                *[false] arbitrary-precision integer
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} arbitrary-precision integers
+        *[false]
+            { $capitalization ->
+            [uppercase] Arbitrary-precision integers
+            *[lowercase] arbitrary-precision integers
+            }
+        }
+    }
+
 data-type-description-uint8 =
     { $count ->
     [0] { $show_count ->
@@ -539,6 +647,114 @@ data-type-description-uint64 =
         }
     }
 
+data-type-description-uint128 =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No 128-bit unsigned integer
+            *[lowercase] no 128-bit unsigned integer
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] 128-bit unsigned integer
+            *[lowercase] 128-bit unsigned integer
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] A 128-bit unsigned integer {$value}
+                *[false] A 128-bit unsigned integer
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] a 128-bit unsigned integer {$value}
+                *[false] a 128-bit unsigned integer
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] 128-bit unsigned integer {$value}
+                *[false] 128-bit unsigned integer
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] 128-bit unsigned integer {$value}
+                *[false] 128-bit unsigned integer
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} 128-bit unsigned integers
+        *[false]
+            { $capitalization ->
+            [uppercase] 128-bit unsigned integers
+            *[lowercase] 128-bit unsigned integers
+            }
+        }
+    }
+
+data-type-description-float16 =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No 16-bit floating point number
+            *[lowercase] no 16-bit floating point number
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] 16-bit floating point number
+            *[lowercase] 16-bit floating point number
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] A 16-bit floating point number {$value}
+                *[false] A 16-bit floating point number
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] a 16-bit floating point number {$value}
+                *[false] a 16-bit floating point number
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] 16-bit floating point number {$value}
+                *[false] 16-bit floating point number
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] 16-bit floating point number {$value}
+                *[false] 16-bit floating point number
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} 16-bit floating point numbers
+        *[false]
+            { $capitalization ->
+            [uppercase] 16-bit floating point numbers
+            *[lowercase] 16-bit floating point numbers
+            }
+        }
+    }
+
 data-type-description-float32 =
     { $count ->
     [0] { $show_count ->
@@ -646,3 +862,381 @@ data-type-description-double =
             }
         }
     }
+
+data-type-description-bool =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No boolean
+            *[lowercase] no boolean
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] Boolean
+            *[lowercase] boolean
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] A boolean {$value}
+                *[false] A boolean
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] a boolean {$value}
+                *[false] a boolean
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] Boolean {$value}
+                *[false] Boolean
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] boolean {$value}
+                *[false] boolean
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} booleans
+        *[false]
+            { $capitalization ->
+            [uppercase] Booleans
+            *[lowercase] booleans
+            }
+        }
+    }
+
+data-type-description-untyped-int =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No untyped integer constant
+            *[lowercase] no untyped integer constant
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] Untyped integer constant
+            *[lowercase] untyped integer constant
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] An untyped integer constant {$value}
+                *[false] An untyped integer constant
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] an untyped integer constant {$value}
+                *[false] an untyped integer constant
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] Untyped integer constant {$value}
+                *[false] Untyped integer constant
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] untyped integer constant {$value}
+                *[false] untyped integer constant
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} untyped integer constants
+        *[false]
+            { $capitalization ->
+            [uppercase] Untyped integer constants
+            *[lowercase] untyped integer constants
+            }
+        }
+    }
+
+data-type-description-untyped-float =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No untyped floating point constant
+            *[lowercase] no untyped floating point constant
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] Untyped floating point constant
+            *[lowercase] untyped floating point constant
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] An untyped floating point constant {$value}
+                *[false] An untyped floating point constant
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] an untyped floating point constant {$value}
+                *[false] an untyped floating point constant
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] Untyped floating point constant {$value}
+                *[false] Untyped floating point constant
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] untyped floating point constant {$value}
+                *[false] untyped floating point constant
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} untyped floating point constants
+        *[false]
+            { $capitalization ->
+            [uppercase] Untyped floating point constants
+            *[lowercase] untyped floating point constants
+            }
+        }
+    }
+
+data-type-description-list =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No list of {$element_type}
+            *[lowercase] no list of {$element_type}
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] List of {$element_type}
+            *[lowercase] list of {$element_type}
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] A list of {$element_type} {$value}
+                *[false] A list of {$element_type}
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] a list of {$element_type} {$value}
+                *[false] a list of {$element_type}
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] List of {$element_type} {$value}
+                *[false] List of {$element_type}
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] list of {$element_type} {$value}
+                *[false] list of {$element_type}
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} lists of {$element_type}
+        *[false]
+            { $capitalization ->
+            [uppercase] Lists of {$element_type}
+            *[lowercase] lists of {$element_type}
+            }
+        }
+    }
+
+data-type-description-map =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No map from {$key_type} to {$value_type}
+            *[lowercase] no map from {$key_type} to {$value_type}
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] Map from {$key_type} to {$value_type}
+            *[lowercase] map from {$key_type} to {$value_type}
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] A map from {$key_type} to {$value_type} {$value}
+                *[false] A map from {$key_type} to {$value_type}
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] a map from {$key_type} to {$value_type} {$value}
+                *[false] a map from {$key_type} to {$value_type}
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] Map from {$key_type} to {$value_type} {$value}
+                *[false] Map from {$key_type} to {$value_type}
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] map from {$key_type} to {$value_type} {$value}
+                *[false] map from {$key_type} to {$value_type}
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} maps from {$key_type} to {$value_type}
+        *[false]
+            { $capitalization ->
+            [uppercase] Maps from {$key_type} to {$value_type}
+            *[lowercase] maps from {$key_type} to {$value_type}
+            }
+        }
+    }
+
+data-type-description-range =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No range of {$element_type}
+            *[lowercase] no range of {$element_type}
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] Range of {$element_type}
+            *[lowercase] range of {$element_type}
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] A range of {$element_type} {$value}
+                *[false] A range of {$element_type}
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] a range of {$element_type} {$value}
+                *[false] a range of {$element_type}
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] Range of {$element_type} {$value}
+                *[false] Range of {$element_type}
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] range of {$element_type} {$value}
+                *[false] range of {$element_type}
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} ranges of {$element_type}
+        *[false]
+            { $capitalization ->
+            [uppercase] Ranges of {$element_type}
+            *[lowercase] ranges of {$element_type}
+            }
+        }
+    }
+
+data-type-description-slice =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No slice of {$element_type}
+            *[lowercase] no slice of {$element_type}
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] Slice of {$element_type}
+            *[lowercase] slice of {$element_type}
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] A slice of {$element_type} {$value}
+                *[false] A slice of {$element_type}
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] a slice of {$element_type} {$value}
+                *[false] a slice of {$element_type}
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] Slice of {$element_type} {$value}
+                *[false] Slice of {$element_type}
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] slice of {$element_type} {$value}
+                *[false] slice of {$element_type}
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} slices of {$element_type}
+        *[false]
+            { $capitalization ->
+            [uppercase] Slices of {$element_type}
+            *[lowercase] slices of {$element_type}
+            }
+        }
+    }
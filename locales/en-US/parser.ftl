@@ -106,6 +106,60 @@ token-description-float =
         }
     }
 
+token-description-bool =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No boolean literal
+            *[lowercase] no boolean literal
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] Boolean literal
+            *[lowercase] boolean literal
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] A boolean literal {$value}
+                *[false] A boolean literal
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] a boolean literal {$value}
+                *[false] a boolean literal
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] Boolean literal {$value}
+                *[false] Boolean literal
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] boolean literal {$value}
+                *[false] boolean literal
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} boolean literals
+        *[false]
+            { $capitalization ->
+            [uppercase] Boolean literals
+            *[lowercase] boolean literals
+            }
+        }
+    }
+
 token-description-identifier =
     { $count ->
     [0] { $show_count ->
@@ -322,6 +376,114 @@ token-description-colon =
         }
     }
 
+token-description-attribute =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No attribute
+            *[lowercase] no attribute
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] Attribute
+            *[lowercase] attribute
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] An attribute {$value}
+                *[false] An attribute
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] an attribute {$value}
+                *[false] an attribute
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] Attribute {$value}
+                *[false] Attribute
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] attribute {$value}
+                *[false] attribute
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} attributes
+        *[false]
+            { $capitalization ->
+            [uppercase] Attributes
+            *[lowercase] attributes
+            }
+        }
+    }
+
+token-description-comma =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No comma
+            *[lowercase] no comma
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] Comma
+            *[lowercase] comma
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] A comma {$value}
+                *[false] A comma
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] a comma {$value}
+                *[false] a comma
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] Comma {$value}
+                *[false] Comma
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] comma {$value}
+                *[false] comma
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} commas
+        *[false]
+            { $capitalization ->
+            [uppercase] Commas
+            *[lowercase] commas
+            }
+        }
+    }
+
 token-description-semicolon =
     { $count ->
     [0] { $show_count ->
@@ -484,6 +646,222 @@ token-description-right-paren =
         }
     }
 
+token-description-left-brace =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No left brace
+            *[lowercase] no left brace
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] Left brace
+            *[lowercase] left brace
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] A left brace {$value}
+                *[false] A left brace
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] a left brace {$value}
+                *[false] a left brace
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] Left brace {$value}
+                *[false] Left brace
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] left brace {$value}
+                *[false] left brace
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} left braces
+        *[false]
+            { $capitalization ->
+            [uppercase] Left braces
+            *[lowercase] left braces
+            }
+        }
+    }
+
+token-description-right-brace =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No right brace
+            *[lowercase] no right brace
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] Right brace
+            *[lowercase] right brace
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] A right brace {$value}
+                *[false] A right brace
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] a right brace {$value}
+                *[false] a right brace
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] Right brace {$value}
+                *[false] Right brace
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] right brace {$value}
+                *[false] right brace
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} right braces
+        *[false]
+            { $capitalization ->
+            [uppercase] Right braces
+            *[lowercase] right braces
+            }
+        }
+    }
+
+token-description-left-bracket =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No left bracket
+            *[lowercase] no left bracket
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] Left bracket
+            *[lowercase] left bracket
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] A left bracket {$value}
+                *[false] A left bracket
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] a left bracket {$value}
+                *[false] a left bracket
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] Left bracket {$value}
+                *[false] Left bracket
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] left bracket {$value}
+                *[false] left bracket
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} left brackets
+        *[false]
+            { $capitalization ->
+            [uppercase] Left brackets
+            *[lowercase] left brackets
+            }
+        }
+    }
+
+token-description-right-bracket =
+    { $count ->
+    [0] { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase] No right bracket
+            *[lowercase] no right bracket
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase] Right bracket
+            *[lowercase] right bracket
+            }
+        }
+    [one]
+        { $show_count ->
+        [true]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] A right bracket {$value}
+                *[false] A right bracket
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] a right bracket {$value}
+                *[false] a right bracket
+                }
+            }
+        *[false]
+            { $capitalization ->
+            [uppercase]
+                { $show_value ->
+                [true] Right bracket {$value}
+                *[false] Right bracket
+                }
+            *[lowercase]
+                { $show_value ->
+                [true] right bracket {$value}
+                *[false] right bracket
+                }
+            }
+        }
+    *[other]
+        { $show_count ->
+        [true] {$count} right brackets
+        *[false]
+            { $capitalization ->
+            [uppercase] Right brackets
+            *[lowercase] right brackets
+            }
+        }
+    }
+
 token-description-single-line-comment =
     { $count ->
     [0] { $show_count ->
@@ -718,4 +1096,15 @@ lexer-error-unexpected-character = Unexpected character: {$character}
 lexer-error-unimplemented-feature = Unimplemented feature: {$feature}
 lexer-error-invalid-number-literal-width = Invalid width {$width} for {$literal_kind} literal. Possible widths are: {$valid_widths}
     .no-width-for-double = Double literals do not support width specification. They are always 64 bits wide.
-
+    .no-width-for-bigint = Bigint literals do not support width specification. They grow as wide as their value needs.
+lexer-error-leading-digit-separator = Digit separator before the first digit
+    .hint = Remove the leading `_`. Separators can only be placed between digits.
+lexer-error-trailing-digit-separator = Digit separator after the last digit
+    .hint = Remove the trailing `_`. Separators can only be placed between digits.
+lexer-error-consecutive-digit-separators = Consecutive digit separators
+    .hint = Use a single `_` between digits.
+lexer-error-missing-exponent-digits = Expected digits in the exponent of the float literal
+lexer-error-missing-hex-float-exponent = Hexadecimal float literal without binary exponent
+    .hint = Add a `p` exponent, a power of two, like `0x1.8p0`.
+lexer-error-float-bigint-literal = Bigint literal with a fractional part or an exponent
+    .hint = Bigints can only hold integers. Write the value without a dot or an exponent, like `15n`.
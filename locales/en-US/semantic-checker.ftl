@@ -5,4 +5,138 @@ semantic-checker-error-invalid-operand =
     .binary-opeartor = binary operator
     .binary-hint =
         {$data_type1} and {$data_type2} cannot be implicitly converted to each other for the {$operator}.
-        Tip: cast one of the operands to the same type as the other.
\ No newline at end of file
+        Tip: cast one of the operands to the same type as the other.
+
+semantic-checker-error-invalid-condition =
+    .message = Expected a boolean condition, found {$data_type}
+    .if-label = condition of this if expression
+    .if-hint = The condition of an if expression must be a boolean.
+    .assert-label = condition of this assertion
+    .assert-hint = The condition of an assertion must be a boolean.
+    .static-assert-label = condition of this static assertion
+    .static-assert-hint = The condition of a static assertion must be a boolean.
+
+semantic-checker-error-non-constant-static-assert =
+    .message = The condition of a static assertion cannot be evaluated at compile time
+    .label = this is only known when the program runs
+    .assert-label = this assertion is checked by the compiler
+    .hint = Use `assert` to check the condition when the program runs.
+
+semantic-checker-error-static-assert-failed =
+    .message = Static assertion failed
+    .label = this condition is false
+    .assert-label = this assertion is checked by the compiler
+    .hint = The compilation stops when the condition of a static assertion is false.
+
+semantic-checker-error-incompatible-branches =
+    .message = The branches of the if expression have incompatible types
+    .then-label = this branch is {$data_type}
+    .else-label = this branch is {$data_type}
+    .hint =
+        {$data_type1} and {$data_type2} cannot be implicitly converted to each other.
+        Tip: cast one of the branches to the same type as the other.
+
+semantic-checker-error-heterogeneous-elements =
+    .list-message = The elements of the list have incompatible types
+    .map-key-message = The keys of the map have incompatible types
+    .map-value-message = The values of the map have incompatible types
+    .first-label = this decides the data type, {$data_type}
+    .element-label = this is {$data_type}
+    .hint =
+        {$data_type1} and {$data_type2} cannot be implicitly converted to each other.
+        Tip: all elements must have the same type.
+
+semantic-checker-error-empty-list =
+    .message = Cannot infer the element type of an empty list
+    .label = this list has no elements
+    .hint = The element type of a list is inferred from its elements, so it must have at least one element.
+
+semantic-checker-error-unhashable-key =
+    .message = {$data_type} cannot be used as map keys
+    .label = this key is {$data_type}
+    .hint = Map keys must be hashable, so only integers and booleans can be used as map keys.
+
+semantic-checker-error-duplicate-key =
+    .message = The key {$key} appears more than once in the map
+    .label = this key is also {$key}
+    .first-label = the key is first used here
+    .hint = A map has one value for each key, so the value of the first entry would be lost. Remove one of the entries.
+
+semantic-checker-error-invalid-range =
+    .message = Cannot create a range from {$data_type1} to {$data_type2}
+    .start-label = the start is {$data_type}
+    .end-label = the end is {$data_type}
+    .hint = The start and end of a range must be integers that can be implicitly converted to each other.
+
+semantic-checker-error-invalid-slice =
+    .target-message = Cannot slice {$data_type}
+    .target-label = this is {$data_type}
+    .target-hint = Only lists and slices can be sliced.
+    .range-message = A list must be sliced by a range, found {$data_type}
+    .range-label = this is {$data_type}
+    .range-hint = Use a range such as `start..end` to slice a list.
+
+semantic-checker-error-slice-out-of-bounds =
+    .negative-message = The slice {$start}..{$end} starts before the start of the list
+    .reversed-message = The slice {$start}..{$end} starts after its end
+    .past-end-message = The slice {$start}..{$end} ends after the end of the list of { $length ->
+        [one] {$length} element
+        *[other] {$length} elements
+        }
+    .range-label = this range is {$start}..{$end}
+    .list-label = this list has { $length ->
+        [one] {$length} element
+        *[other] {$length} elements
+        }
+    .hint = A slice can only view elements of the list, from index 0 up to the length of the list.
+
+semantic-checker-error-invalid-attribute =
+    .unknown-message = Unknown attribute `@{$name}`
+    .unknown-label = this attribute is not known
+    .unknown-similar-hint = Did you mean `@{$name}`?
+    .unknown-hint = The known attributes are {$names}.
+    .arguments-message = Too many arguments for attribute `@{$name}`
+    .arguments-label = this attribute has too many arguments
+    .arguments-hint = `@{$name}` takes { $count ->
+        [0] no arguments
+        [one] at most {$count} argument
+        *[other] at most {$count} arguments
+        }.
+    .target-message = Attribute `@{$name}` cannot be applied to this statement
+    .target-label = this attribute is not allowed here
+    .target-hint = `@{$name}` can only be applied to functions.
+
+semantic-checker-error-nested-defer =
+    .message = A defer statement cannot be deferred
+    .label = this defer statement is deferred
+    .outer-label = by this defer statement
+    .hint = Deferred statements already run in reverse order when the block exits, so defer the statement directly.
+
+semantic-checker-error-constant-overflow =
+    .message = Constant {$value} does not fit in {$data_type}
+    .constant-label = this constant is {$value}
+    .context-label = this is {$data_type}
+    .hint = An untyped constant takes the data type of its context, {$data_type} here, and its value must be representable in it.
+    .evaluation-message = The constant expression overflows while it is evaluated
+    .evaluation-label = this overflows
+    .evaluation-hint =
+        Integer constants are evaluated exactly up to {$max_bits} bits and floating point constants with doubles.
+        Tip: add the `n` suffix to an integer literal to compute with bigint when the program runs instead.
+
+semantic-checker-error-invalid-constant-operation =
+    .division-by-zero-message = The constant expression divides by zero
+    .division-by-zero-label = this divisor is zero
+    .division-by-zero-hint = Division and remainder by zero have no value.
+    .negative-exponent-message = An integer constant cannot be raised to a negative power
+    .negative-exponent-label = this exponent is negative
+    .negative-exponent-hint =
+        The result would not be an integer.
+        Tip: use a floating point constant such as `2.0` to compute a fraction.
+
+semantic-checker-warning-non-portable-int-width =
+    .message = This conversion relies on {$data_type} being {$bit_size} bits wide on {$target}
+    .label = {$data_type} is converted here
+    .context-label = to {$data_type} of this expression
+    .hint =
+        {$data_type} is as wide as a pointer, so it is 32 bits on some targets and 64 bits on others.
+        Tip: use a fixed-width integer type such as {$data_type1} or {$data_type2} instead.
@@ -14,7 +14,11 @@ use clap::{
 };
 use termcolor::WriteColor;
 
-use crate::common::config::CompilerBackend;
+use crate::common::config::{
+	CompilerBackend,
+	OverflowPolicy,
+};
+use crate::common::target::Target;
 use crate::common::utils::is_color_output_disabled;
 use crate::common::ExitCode;
 use crate::{format_datetime, localdate, t};
@@ -119,6 +123,27 @@ fn attach_help_template(cmd: &Command) -> Command
 	cmd.clone().help_template(tmpl)
 }
 
+/// Create the `overflow` argument shared by the `compile`
+/// and `run` subcommands
+///
+/// # Arguments
+///
+/// * `help` - The help message of the argument.
+///
+/// # Returns
+///
+/// The `overflow` argument.
+fn overflow_arg(help: String) -> Arg
+{
+	Arg::new("overflow")
+		.help(help)
+		.long("overflow")
+		.value_parser(OverflowPolicy::get_allowed_variants())
+		.default_value("checked")
+		.action(ArgAction::Set)
+		.required(false)
+}
+
 /// Create `compile` subcommand for Clap
 fn compile_subcommand() -> Command
 {
@@ -148,6 +173,28 @@ fn compile_subcommand() -> Command
 				.default_value("LLVM")
 				.action(ArgAction::Set)
 				.required(false),
+		)
+		.arg(overflow_arg(t!(
+			"cli-subcmd-compile-help.arg-overflow"
+		)))
+		.arg(
+			Arg::new("target")
+				.help(t!("cli-subcmd-compile-help.arg-target"))
+				.short('t')
+				.long("target")
+				.value_parser(Target::get_allowed_variants())
+				.default_value("host")
+				.action(ArgAction::Set)
+				.required(false),
+		)
+		.arg(
+			Arg::new("no-debug-assertions")
+				.help(t!(
+					"cli-subcmd-compile-help.arg-no-debug-assertions"
+				))
+				.long("no-debug-assertions")
+				.action(ArgAction::SetTrue)
+				.required(false),
 		);
 
 	attach_help_template(&cmd)
@@ -169,7 +216,10 @@ fn run_subcommand() -> Command
 				.long("no-jit")
 				.action(ArgAction::SetTrue)
 				.required(false),
-		);
+		)
+		.arg(overflow_arg(t!(
+			"cli-subcmd-run-help.arg-overflow"
+		)));
 
 	attach_help_template(&cmd)
 }
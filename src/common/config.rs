@@ -7,6 +7,8 @@
 use clap::ArgMatches;
 use termcolor::ColorChoice;
 
+use super::target::Target;
+
 /// Configuration enum for the program.
 #[derive(Debug, Clone)]
 pub struct Config
@@ -97,6 +99,64 @@ impl Config
 		}
 	}
 
+	/// Get the integer overflow policy of the program.
+	///
+	/// # Returns
+	///
+	/// The overflow policy of the inner configuration, or
+	/// `Checked` if there is no inner configuration.
+	pub fn overflow_policy(&self) -> OverflowPolicy
+	{
+		match &self.inner
+		{
+			Some(InnerConfig::Interpreter(config)) =>
+			{
+				config.overflow_policy
+			}
+			Some(InnerConfig::Compiler(config)) =>
+			{
+				config.overflow_policy
+			}
+			None => OverflowPolicy::Checked,
+		}
+	}
+
+	/// Check if debug assertions are enabled.
+	///
+	/// # Returns
+	///
+	/// `false` if they are disabled in the compiler mode,
+	/// otherwise `true`.
+	pub fn debug_assertions(&self) -> bool
+	{
+		match &self.inner
+		{
+			Some(InnerConfig::Compiler(config)) =>
+			{
+				config.debug_assertions
+			}
+			_ => true,
+		}
+	}
+
+	/// Get the target machine of the program.
+	///
+	/// # Returns
+	///
+	/// The target of the compiler mode, or the host machine
+	/// in other modes.
+	pub fn target(&self) -> Target
+	{
+		match &self.inner
+		{
+			Some(InnerConfig::Compiler(config)) =>
+			{
+				config.target.clone()
+			}
+			_ => Target::host(),
+		}
+	}
+
 	/// Create inner configuration from the `Config`.
 	/// If the subcommand is not recognized, it will return
 	/// `None`.
@@ -193,12 +253,71 @@ impl From<ArgMatches> for GlobalConfig
 	}
 }
 
+/// The behavior of integer arithmetic when the result does
+/// not fit in the data type of the operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy
+{
+	/// Trap with a runtime error.
+	Checked,
+	/// Wrap around the boundary of the data type.
+	Wrapping,
+	/// Clamp to the minimum or maximum of the data type.
+	Saturating,
+}
+
+/// Implementation of `OverflowPolicy`.
+impl OverflowPolicy
+{
+	/// Get the names of the overflow policies.
+	pub fn get_allowed_variants() -> [&'static str; 3]
+	{
+		["checked", "wrapping", "saturating"]
+	}
+}
+
+/// `From<String>` implementation for `OverflowPolicy`.
+impl From<String> for OverflowPolicy
+{
+	/// Convert a string into an `OverflowPolicy`.
+	fn from(value: String) -> Self
+	{
+		match value.to_lowercase().as_str()
+		{
+			"wrapping" => OverflowPolicy::Wrapping,
+			"saturating" => OverflowPolicy::Saturating,
+			_ => OverflowPolicy::Checked,
+		}
+	}
+}
+
+/// Get the overflow policy from `ArgMatches`.
+///
+/// # Arguments
+///
+/// * `matches` - The `ArgMatches` of a subcommand.
+///
+/// # Returns
+///
+/// The overflow policy, `Checked` if it is not provided.
+fn overflow_policy_from_arg_matches(
+	matches: &ArgMatches,
+) -> OverflowPolicy
+{
+	matches
+		.get_one::<String>("overflow")
+		.map(|v| OverflowPolicy::from(v.to_owned()))
+		.unwrap_or(OverflowPolicy::Checked)
+}
+
 /// Configuration of the program's interpreter mode.
 #[derive(Debug, Clone)]
 pub struct InterpreterModeConfig
 {
 	/// Run the program using JIT execution engine.
 	pub jit_enabled: bool,
+	/// The integer overflow policy of arithmetic operations.
+	pub overflow_policy: OverflowPolicy,
 }
 
 /// `Default` implementation for `InterpreterModeConfig`.
@@ -208,7 +327,10 @@ impl Default for InterpreterModeConfig
 	/// values.
 	fn default() -> Self
 	{
-		InterpreterModeConfig { jit_enabled: true }
+		InterpreterModeConfig {
+			jit_enabled: true,
+			overflow_policy: OverflowPolicy::Checked,
+		}
 	}
 }
 
@@ -222,6 +344,9 @@ impl From<ArgMatches> for InterpreterModeConfig
 	{
 		InterpreterModeConfig {
 			jit_enabled: !matches.get_flag("no-jit"),
+			overflow_policy: overflow_policy_from_arg_matches(
+				&matches,
+			),
 		}
 	}
 }
@@ -272,6 +397,12 @@ pub struct CompilerModeConfig
 	pub output_file: Option<String>,
 	/// The backend of the compiler.
 	pub backend: CompilerBackend,
+	/// The integer overflow policy of arithmetic operations.
+	pub overflow_policy: OverflowPolicy,
+	/// The target machine of the compiler.
+	pub target: Target,
+	/// Keep `assert` statements in the compiled program.
+	pub debug_assertions: bool,
 }
 
 /// `Default` implementation for `CompilerModeConfig`.
@@ -284,6 +415,9 @@ impl Default for CompilerModeConfig
 		CompilerModeConfig {
 			output_file: None,
 			backend: CompilerBackend::LLVM,
+			overflow_policy: OverflowPolicy::Checked,
+			target: Target::host(),
+			debug_assertions: true,
 		}
 	}
 }
@@ -305,6 +439,15 @@ impl From<ArgMatches> for CompilerModeConfig
 				.map(|v| v.to_owned())
 				.map(|v| CompilerBackend::from(v))
 				.unwrap_or_else(|| CompilerBackend::LLVM),
+			overflow_policy: overflow_policy_from_arg_matches(
+				&matches,
+			),
+			target: matches
+				.get_one::<String>("target")
+				.map(|v| Target::from(v.to_owned()))
+				.unwrap_or_else(Target::host),
+			debug_assertions: !matches
+				.get_flag("no-debug-assertions"),
 		}
 	}
 }
@@ -455,7 +598,12 @@ mod tests
 	#[test]
 	fn test_compiler_mode_config_from_arg_matches()
 	{
-		use super::{CompilerBackend, CompilerModeConfig};
+		use super::{
+			CompilerBackend,
+			CompilerModeConfig,
+			OverflowPolicy,
+		};
+		use crate::common::target::Target;
 
 		let command = Command::new("test")
 			.arg(
@@ -481,10 +629,35 @@ mod tests
 					.short('b')
 					.long("backend")
 					.action(ArgAction::Set),
+			)
+			.arg(
+				Arg::new("overflow")
+					.long("overflow")
+					.action(ArgAction::Set),
+			)
+			.arg(
+				Arg::new("target")
+					.long("target")
+					.action(ArgAction::Set),
+			)
+			.arg(
+				Arg::new("no-debug-assertions")
+					.long("no-debug-assertions")
+					.action(ArgAction::SetTrue),
 			);
 
 		let matches = command.clone().get_matches_from(vec![
-			"test", "-d", "-o", "output", "-b", "llvm",
+			"test",
+			"-d",
+			"-o",
+			"output",
+			"-b",
+			"llvm",
+			"--overflow",
+			"wrapping",
+			"--target",
+			"wasm32",
+			"--no-debug-assertions",
 		]);
 		let config = Config::from_arg_matches(
 			matches,
@@ -508,12 +681,24 @@ mod tests
 			Some("output".to_owned())
 		);
 		assert_eq!(inner.backend, CompilerBackend::LLVM);
+		assert_eq!(
+			inner.overflow_policy,
+			OverflowPolicy::Wrapping
+		);
+		assert_eq!(inner.target.pointer_width, 32);
+		assert!(!inner.debug_assertions);
 
 		let matches = command.clone().get_matches_from(vec![
 			"test", "-d", "-c", "always", "-o", "output",
 		]);
 		let config = CompilerModeConfig::from(matches);
 		assert_eq!(config.backend, CompilerBackend::LLVM);
+		assert_eq!(
+			config.overflow_policy,
+			OverflowPolicy::Checked
+		);
+		assert_eq!(config.target, Target::host());
+		assert!(config.debug_assertions);
 	}
 
 	#[test]
@@ -536,11 +721,20 @@ mod tests
 				Arg::new("no-jit")
 					.long("no-jit")
 					.action(ArgAction::SetTrue),
+			)
+			.arg(
+				Arg::new("overflow")
+					.long("overflow")
+					.action(ArgAction::Set),
 			);
 
-		let matches = command
-			.clone()
-			.get_matches_from(vec!["test", "-d", "--no-jit"]);
+		let matches = command.clone().get_matches_from(vec![
+			"test",
+			"-d",
+			"--no-jit",
+			"--overflow",
+			"saturating",
+		]);
 		let config = Config::from_arg_matches(
 			matches,
 			Some(ApplicationMode::Interpreter),
@@ -559,6 +753,26 @@ mod tests
 		)
 		.unwrap();
 		assert_eq!(inner.jit_enabled, false);
+		assert_eq!(
+			inner.overflow_policy,
+			super::OverflowPolicy::Saturating
+		);
+		assert_eq!(
+			Config::new(
+				Default::default(),
+				InnerConfig::Interpreter(inner)
+			)
+			.overflow_policy(),
+			super::OverflowPolicy::Saturating
+		);
+		assert_eq!(
+			Config::new(
+				Default::default(),
+				InnerConfig::Interpreter(Default::default())
+			)
+			.target(),
+			crate::common::target::Target::host()
+		);
 	}
 
 	#[test]
@@ -595,6 +809,33 @@ mod tests
 		);
 	}
 
+	#[test]
+	fn test_overflow_policy_from_string()
+	{
+		use super::OverflowPolicy;
+
+		assert_eq!(
+			OverflowPolicy::from("checked".to_owned()),
+			OverflowPolicy::Checked
+		);
+		assert_eq!(
+			OverflowPolicy::from("Wrapping".to_owned()),
+			OverflowPolicy::Wrapping
+		);
+		assert_eq!(
+			OverflowPolicy::from("saturating".to_owned()),
+			OverflowPolicy::Saturating
+		);
+		assert_eq!(
+			OverflowPolicy::from("invalid".to_owned()),
+			OverflowPolicy::Checked
+		);
+		assert_eq!(
+			Config::default().overflow_policy(),
+			OverflowPolicy::Checked
+		);
+	}
+
 	#[test]
 	fn test_global_config_default()
 	{
@@ -611,12 +852,20 @@ mod tests
 	#[test]
 	fn test_compiler_mode_config_default()
 	{
-		use super::{CompilerBackend, CompilerModeConfig};
+		use super::{
+			CompilerBackend,
+			CompilerModeConfig,
+			OverflowPolicy,
+		};
 
 		let config = CompilerModeConfig::default();
 
 		assert_eq!(config.output_file, None);
 		assert_eq!(config.backend, CompilerBackend::LLVM);
+		assert_eq!(
+			config.overflow_policy,
+			OverflowPolicy::Checked
+		);
 	}
 
 	#[test]
@@ -627,5 +876,9 @@ mod tests
 		let config = InterpreterModeConfig::default();
 
 		assert_eq!(config.jit_enabled, true);
+		assert_eq!(
+			config.overflow_policy,
+			super::OverflowPolicy::Checked
+		);
 	}
 }
@@ -8,6 +8,7 @@
 pub mod config;
 pub mod error;
 pub mod i18n;
+pub mod target;
 pub mod utils;
 
 use std::path::PathBuf;
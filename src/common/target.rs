@@ -0,0 +1,182 @@
+//! # Target (target) module
+//!
+//! The `target` module describes the machine the program is
+//! compiled for. The description is used by the compiler to
+//! decide the size of target dependent data types such as
+//! `int` and `uint`.
+
+use crate::ternary;
+
+/// Byte order of the target machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endianness
+{
+	/// Least significant byte first.
+	Little,
+	/// Most significant byte first.
+	Big,
+}
+
+/// Description of the target machine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Target
+{
+	/// The name of the target.
+	pub name: String,
+	/// The width of a pointer in bits.
+	pub pointer_width: u32,
+	/// The byte order of the target.
+	pub endianness: Endianness,
+}
+
+/// `Default` implementation for `Target`.
+impl Default for Target
+{
+	/// Create a `Target` describing the host machine.
+	fn default() -> Self
+	{
+		Target::host()
+	}
+}
+
+/// Implementation of `Target`.
+impl Target
+{
+	/// Pointer widths of all supported targets.
+	pub const POINTER_WIDTHS: [u32; 2] = [32, 64];
+
+	/// Get the names of the supported targets.
+	pub fn get_allowed_variants() -> [&'static str; 8]
+	{
+		[
+			"host",
+			"x86_64",
+			"i686",
+			"aarch64",
+			"armv7",
+			"wasm32",
+			"powerpc64",
+			"mips",
+		]
+	}
+
+	/// Create a `Target` describing the host machine.
+	///
+	/// # Returns
+	///
+	/// The host target.
+	pub fn host() -> Self
+	{
+		Target {
+			name: "host".to_owned(),
+			pointer_width: usize::BITS,
+			endianness: ternary!(
+				cfg!(target_endian = "big"),
+				Endianness::Big,
+				Endianness::Little
+			),
+		}
+	}
+
+	/// Create a copy of the target with a different pointer
+	/// width.
+	///
+	/// # Arguments
+	///
+	/// * `pointer_width` - The pointer width in bits.
+	///
+	/// # Returns
+	///
+	/// The target with the given pointer width.
+	pub fn with_pointer_width(
+		&self,
+		pointer_width: u32,
+	) -> Self
+	{
+		Target {
+			pointer_width,
+			..self.clone()
+		}
+	}
+}
+
+/// `From<String>` implementation for `Target`.
+impl From<String> for Target
+{
+	/// Convert a target name into a `Target`.
+	/// Unknown names are treated as the host machine.
+	fn from(value: String) -> Self
+	{
+		let (pointer_width, endianness) =
+			match value.to_lowercase().as_str()
+			{
+				"x86_64" | "aarch64" => (64, Endianness::Little),
+				"i686" | "armv7" | "wasm32" =>
+				{
+					(32, Endianness::Little)
+				}
+				"powerpc64" => (64, Endianness::Big),
+				"mips" => (32, Endianness::Big),
+				_ => return Target::host(),
+			};
+
+		Target {
+			name: value.to_lowercase(),
+			pointer_width,
+			endianness,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::{Endianness, Target};
+
+	#[test]
+	fn test_target_from_string()
+	{
+		let target = Target::from("x86_64".to_owned());
+		assert_eq!(target.pointer_width, 64);
+		assert_eq!(target.endianness, Endianness::Little);
+
+		let target = Target::from("WASM32".to_owned());
+		assert_eq!(target.name, "wasm32");
+		assert_eq!(target.pointer_width, 32);
+
+		let target = Target::from("mips".to_owned());
+		assert_eq!(target.pointer_width, 32);
+		assert_eq!(target.endianness, Endianness::Big);
+
+		assert_eq!(
+			Target::from("invalid".to_owned()),
+			Target::host()
+		);
+	}
+
+	#[test]
+	fn test_target_host()
+	{
+		let target = Target::default();
+
+		assert_eq!(target.name, "host");
+		assert_eq!(target.pointer_width, usize::BITS);
+		assert_eq!(
+			target.with_pointer_width(32).pointer_width,
+			32
+		);
+	}
+
+	#[test]
+	fn test_target_get_allowed_variants()
+	{
+		for name in Target::get_allowed_variants()
+		{
+			let target = Target::from(name.to_owned());
+			assert!(
+				Target::POINTER_WIDTHS
+					.contains(&target.pointer_width)
+			);
+		}
+	}
+}
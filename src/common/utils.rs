@@ -153,6 +153,70 @@ pub fn is_color_output_disabled() -> bool
 		|| !std::io::stderr().is_terminal();
 }
 
+/// Compute the Levenshtein distance between two strings.
+///
+/// # Arguments
+///
+/// * `lhs` - The first string.
+/// * `rhs` - The second string.
+///
+/// # Returns
+///
+/// The number of inserted, removed or replaced characters
+/// needed to turn `lhs` into `rhs`.
+pub fn edit_distance(lhs: &str, rhs: &str) -> usize
+{
+	let rhs: Vec<char> = rhs.chars().collect();
+	let mut previous_row: Vec<usize> =
+		(0 ..= rhs.len()).collect();
+
+	for (i, lhs_char) in lhs.chars().enumerate()
+	{
+		let mut current_row = vec![i + 1];
+		for (j, rhs_char) in rhs.iter().enumerate()
+		{
+			let cost = usize::from(lhs_char != *rhs_char);
+			current_row.push(
+				(previous_row[j] + cost)
+					.min(previous_row[j + 1] + 1)
+					.min(current_row[j] + 1),
+			);
+		}
+		previous_row = current_row;
+	}
+
+	previous_row[rhs.len()]
+}
+
+/// Find the candidate most similar to a name, to suggest it
+/// when the name is unknown.
+///
+/// # Arguments
+///
+/// * `name` - The unknown name.
+/// * `candidates` - The known names.
+///
+/// # Returns
+///
+/// The closest candidate if it is close enough to be a
+/// typo of the name, otherwise `None`.
+pub fn find_similar_name<'a>(
+	name: &str,
+	candidates: &[&'a str],
+) -> Option<&'a str>
+{
+	let max_distance = (name.chars().count() / 3).max(1);
+
+	candidates
+		.iter()
+		.map(|candidate| {
+			(edit_distance(name, candidate), *candidate)
+		})
+		.filter(|(distance, _)| *distance <= max_distance)
+		.min_by_key(|(distance, _)| *distance)
+		.map(|(_, candidate)| candidate)
+}
+
 /// Helper to get certain enum variant without explicit
 /// pattern matching
 ///
@@ -288,6 +352,42 @@ mod tests
 		assert_eq!(content.unwrap(), "echo \"Hello, World!\"");
 	}
 
+	#[test]
+	fn test_edit_distance()
+	{
+		assert_eq!(super::edit_distance("", ""), 0);
+		assert_eq!(super::edit_distance("inline", "inline"), 0);
+		assert_eq!(super::edit_distance("inlin", "inline"), 1);
+		assert_eq!(
+			super::edit_distance("kitten", "sitting"),
+			3
+		);
+		assert_eq!(super::edit_distance("", "test"), 4);
+	}
+
+	#[test]
+	fn test_find_similar_name()
+	{
+		let candidates = ["deprecated", "inline", "test"];
+
+		assert_eq!(
+			super::find_similar_name("depracated", &candidates),
+			Some("deprecated")
+		);
+		assert_eq!(
+			super::find_similar_name("tset", &candidates),
+			None
+		);
+		assert_eq!(
+			super::find_similar_name("tests", &candidates),
+			Some("test")
+		);
+		assert_eq!(
+			super::find_similar_name("cold", &candidates),
+			None
+		);
+	}
+
 	#[test]
 	fn test_localdate()
 	{
@@ -1,6 +1,7 @@
 use smol_str::SmolStr;
 
 use super::data_type::DataType;
+use crate::common::config::OverflowPolicy;
 use crate::parser::ast::{self, GetSpan};
 use crate::parser::span::Span;
 
@@ -34,12 +35,61 @@ pub type AnnotatedGroupExpr<E> =
 	AnnotatedAst<ast::GroupingExpr<E>, DataType>;
 
 /// Annotated binary expression.
-pub type AnnotatedBinaryExpr<E> =
-	AnnotatedAst<ast::BinaryExpr<E>, DataType>;
+/// Arithmetic operations on fixed width integers also carry
+/// the overflow policy the backend should use. Bigint and
+/// floating point operations never overflow, so they have
+/// none.
+#[derive(Debug, Clone)]
+pub struct AnnotatedBinaryExpr<E>
+where
+	E: ast::GetSpan,
+{
+	pub inner: ast::BinaryExpr<E>,
+	pub data_type: DataType,
+	pub overflow_policy: Option<OverflowPolicy>,
+}
 
 /// Annotated unary expression.
-pub type AnnotatedUnaryExpr<E> =
-	AnnotatedAst<ast::UnaryExpr<E>, DataType>;
+/// Negating the minimum value of a signed integer
+/// overflows, so the overflow policy is recorded like on
+/// binary expressions.
+#[derive(Debug, Clone)]
+pub struct AnnotatedUnaryExpr<E>
+where
+	E: ast::GetSpan,
+{
+	pub inner: ast::UnaryExpr<E>,
+	pub data_type: DataType,
+	pub overflow_policy: Option<OverflowPolicy>,
+}
+
+/// Annotated block expression.
+pub type AnnotatedBlockExpr<E, S> =
+	AnnotatedAst<ast::BlockExpr<E, S>, DataType>;
+
+/// Annotated if expression.
+pub type AnnotatedIfExpr<E> =
+	AnnotatedAst<ast::IfExpr<E>, DataType>;
+
+/// Annotated list expression.
+/// The data type is `List` of the element data type.
+pub type AnnotatedListExpr<E> =
+	AnnotatedAst<ast::ListExpr<E>, DataType>;
+
+/// Annotated map expression.
+/// The data type is `Map` of the key and value data types.
+pub type AnnotatedMapExpr<E> =
+	AnnotatedAst<ast::MapExpr<E>, DataType>;
+
+/// Annotated range expression.
+/// The data type is `Range` of the bound data type.
+pub type AnnotatedRangeExpr<E> =
+	AnnotatedAst<ast::RangeExpr<E>, DataType>;
+
+/// Annotated slice expression.
+/// The data type is `Slice` of the element data type.
+pub type AnnotatedSliceExpr<E> =
+	AnnotatedAst<ast::SliceExpr<E>, DataType>;
 
 /// Annotated Expression
 #[derive(Debug, Clone)]
@@ -49,6 +99,17 @@ pub enum AnnotatedExpression
 	Group(AnnotatedGroupExpr<AnnotatedExpression>),
 	Binary(AnnotatedBinaryExpr<AnnotatedExpression>),
 	Unary(AnnotatedUnaryExpr<AnnotatedExpression>),
+	Block(
+		AnnotatedBlockExpr<
+			AnnotatedExpression,
+			AnnotatedStatement<AnnotatedExpression>,
+		>,
+	),
+	If(AnnotatedIfExpr<AnnotatedExpression>),
+	List(AnnotatedListExpr<AnnotatedExpression>),
+	Map(AnnotatedMapExpr<AnnotatedExpression>),
+	Range(AnnotatedRangeExpr<AnnotatedExpression>),
+	Slice(AnnotatedSliceExpr<AnnotatedExpression>),
 }
 
 impl GetSpan for AnnotatedExpression
@@ -73,6 +134,27 @@ impl GetSpan for AnnotatedExpression
 			{
 				unary.inner.get_span()
 			}
+			AnnotatedExpression::Block(block) =>
+			{
+				block.inner.get_span()
+			}
+			AnnotatedExpression::If(if_expr) =>
+			{
+				if_expr.inner.get_span()
+			}
+			AnnotatedExpression::List(list) =>
+			{
+				list.inner.get_span()
+			}
+			AnnotatedExpression::Map(map) => map.inner.get_span(),
+			AnnotatedExpression::Range(range) =>
+			{
+				range.inner.get_span()
+			}
+			AnnotatedExpression::Slice(slice) =>
+			{
+				slice.inner.get_span()
+			}
 		}
 	}
 }
@@ -94,6 +176,12 @@ impl AnnotatedExpression
 				&binary.data_type
 			}
 			AnnotatedExpression::Unary(unary) => &unary.data_type,
+			AnnotatedExpression::Block(block) => &block.data_type,
+			AnnotatedExpression::If(if_expr) => &if_expr.data_type,
+			AnnotatedExpression::List(list) => &list.data_type,
+			AnnotatedExpression::Map(map) => &map.data_type,
+			AnnotatedExpression::Range(range) => &range.data_type,
+			AnnotatedExpression::Slice(slice) => &slice.data_type,
 		}
 	}
 }
@@ -106,6 +194,34 @@ pub type AnnotatedExpressionStmt<E> =
 pub type AnnotatedEchoStmt<E> =
 	AnnotatedAst<ast::EchoStmt<E>, Option<DataType>>;
 
+/// Annotated assert statement.
+/// Disabled assertions are checked but not compiled.
+#[derive(Debug, Clone)]
+pub struct AnnotatedAssertStmt<E>
+where
+	E: ast::GetSpan,
+{
+	pub inner: ast::AssertStmt<E>,
+	pub data_type: Option<DataType>,
+	pub is_enabled: bool,
+}
+
+/// Annotated static assert statement.
+/// It is evaluated by the semantic checker, so it is not
+/// compiled.
+pub type AnnotatedStaticAssertStmt<E> =
+	AnnotatedAst<ast::AssertStmt<E>, Option<DataType>>;
+
+/// Annotated attributed statement.
+pub type AnnotatedAttributedStmt<E> = AnnotatedAst<
+	ast::AttributedStmt<E, AnnotatedStatement<E>>,
+	Option<DataType>,
+>;
+
+/// Annotated defer statement.
+pub type AnnotatedDeferStmt<S> =
+	AnnotatedAst<ast::DeferStmt<S>, Option<DataType>>;
+
 /// Annotated function declaration.
 pub type AnnotatedFunctionDeclStmt<S> =
 	AnnotatedAst<ast::FunctionDeclStmt<S>, Option<DataType>>;
@@ -118,6 +234,10 @@ where
 {
 	Expression(AnnotatedExpressionStmt<E>),
 	Echo(AnnotatedEchoStmt<E>),
+	Assert(AnnotatedAssertStmt<E>),
+	StaticAssert(AnnotatedStaticAssertStmt<E>),
+	Attributed(AnnotatedAttributedStmt<E>),
+	Defer(AnnotatedDeferStmt<AnnotatedStatement<E>>),
 	FunctionDecl(
 		AnnotatedFunctionDeclStmt<AnnotatedStatement<E>>,
 	),
@@ -139,6 +259,22 @@ where
 			{
 				echo.inner.get_span()
 			}
+			AnnotatedStatement::Assert(assert) =>
+			{
+				assert.inner.get_span()
+			}
+			AnnotatedStatement::StaticAssert(assert) =>
+			{
+				assert.inner.get_span()
+			}
+			AnnotatedStatement::Attributed(attributed) =>
+			{
+				attributed.inner.get_span()
+			}
+			AnnotatedStatement::Defer(defer) =>
+			{
+				defer.inner.get_span()
+			}
 			AnnotatedStatement::FunctionDecl(func) =>
 			{
 				func.inner.get_span()
@@ -0,0 +1,1090 @@
+use std::fmt::Display;
+
+use num_bigint::BigInt;
+use num_traits::{
+	FromPrimitive,
+	Pow,
+	Signed,
+	ToPrimitive,
+	Zero,
+};
+
+use super::annotated_ast::AnnotatedExpression;
+use super::data_type::{DataType, KnownDataType};
+use super::float_format::FormatFloat;
+use crate::common::config::OverflowPolicy;
+use crate::common::target::Target;
+use crate::parser::ast::{self, GetSpan};
+use crate::parser::span::Span;
+use crate::ternary;
+
+/// Largest number of bits of an untyped integer constant.
+/// It only bounds the memory of evaluating constants such
+/// as `10 ** 4000000000`, no data type is that wide.
+pub const MAX_INT_BITS: u64 = 1 << 16;
+
+/// Value of an untyped constant expression.
+/// Untyped constants are evaluated with the widest
+/// precision available before they are converted to the
+/// data type of their context, so only the final value has
+/// to fit in that data type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constant
+{
+	/// Untyped integer constant, evaluated exactly
+	Int(BigInt),
+	/// Untyped floating point constant
+	Float(f64),
+}
+
+/// Value of a map key that is known at compile time
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConstantKey
+{
+	/// Integer key of any integer data type
+	Int(BigInt),
+	/// Boolean key
+	Bool(bool),
+}
+
+/// Reason an expression can't be evaluated as an untyped
+/// constant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstantErrorCode
+{
+	/// Expression is only known when the program runs
+	NotConstant,
+	/// Value has more than `MAX_INT_BITS` bits or does not
+	/// fit in a double
+	Overflow,
+	/// Division or remainder by zero
+	DivisionByZero,
+	/// Integer raised to a negative power
+	NegativeExponent,
+}
+
+/// Error of evaluating an untyped constant expression
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConstantError
+{
+	/// The error code
+	pub code: ConstantErrorCode,
+	/// The span of the operation that has no value
+	pub span: Option<Span>,
+}
+
+/// `Display` implementation for `Constant`
+impl Display for Constant
+{
+	fn fmt(
+		&self,
+		f: &mut std::fmt::Formatter<'_>,
+	) -> std::fmt::Result
+	{
+		match self
+		{
+			Constant::Int(value) => write!(f, "{}", value),
+			Constant::Float(value) =>
+			{
+				write!(f, "{}", value.format_float())
+			}
+		}
+	}
+}
+
+/// `Display` implementation for `ConstantKey`
+impl Display for ConstantKey
+{
+	fn fmt(
+		&self,
+		f: &mut std::fmt::Formatter<'_>,
+	) -> std::fmt::Result
+	{
+		match self
+		{
+			ConstantKey::Int(value) => write!(f, "{}", value),
+			ConstantKey::Bool(value) => write!(f, "{}", value),
+		}
+	}
+}
+
+/// Implementation for `Constant`
+impl Constant
+{
+	/// Evaluate an untyped constant expression
+	///
+	/// # Arguments
+	///
+	/// * `expression` - The annotated expression
+	///
+	/// # Returns
+	///
+	/// The value of the expression if it only consists of
+	/// untyped literals and can be evaluated at compile time,
+	/// otherwise `None`
+	pub fn evaluate(
+		expression: &AnnotatedExpression,
+	) -> Option<Constant>
+	{
+		Constant::try_evaluate(expression).ok()
+	}
+
+	/// Evaluate an untyped constant expression
+	///
+	/// # Arguments
+	///
+	/// * `expression` - The annotated expression
+	///
+	/// # Returns
+	///
+	/// The value of the expression
+	///
+	/// # Errors
+	///
+	/// If the expression does not only consist of untyped
+	/// literals, it will return `NotConstant`. If an
+	/// operation overflows, divides by zero or raises an
+	/// integer to a negative power, it will return the error
+	/// of the first such operation.
+	pub fn try_evaluate(
+		expression: &AnnotatedExpression,
+	) -> Result<Constant, ConstantError>
+	{
+		let not_constant = Err(ConstantError {
+			code: ConstantErrorCode::NotConstant,
+			span: expression.get_span(),
+		});
+
+		match expression
+		{
+			AnnotatedExpression::Literal(literal) =>
+			{
+				match literal.inner.value
+				{
+					ast::Value::UntypedInt(value) =>
+					{
+						Ok(Constant::Int(value.into()))
+					}
+					ast::Value::UntypedFloat(value) =>
+					{
+						Ok(Constant::Float(value))
+					}
+					_ => not_constant,
+				}
+			}
+			AnnotatedExpression::Group(group) =>
+			{
+				Constant::try_evaluate(&group.inner.expression)
+			}
+			AnnotatedExpression::Unary(unary) =>
+			{
+				let right =
+					Constant::try_evaluate(&unary.inner.right)?;
+				match unary.inner.operator
+				{
+					ast::UnaryOperator::Negate => match right
+					{
+						Constant::Int(value) =>
+						{
+							Ok(Constant::Int(-value))
+						}
+						Constant::Float(value) =>
+						{
+							Ok(Constant::Float(-value))
+						}
+					},
+				}
+			}
+			AnnotatedExpression::Binary(binary) =>
+			{
+				let left =
+					Constant::try_evaluate(&binary.inner.left)?;
+				let right =
+					Constant::try_evaluate(&binary.inner.right)?;
+				Constant::evaluate_binary(
+					&binary.inner.operator,
+					&left,
+					&right,
+				)
+				.map_err(|code| {
+					// overflow is caused by the whole operation,
+					// the other errors by the right operand
+					ConstantError {
+						code,
+						span: ternary!(
+							code == ConstantErrorCode::Overflow,
+							binary.inner.get_span(),
+							binary.inner.right.get_span()
+						),
+					}
+				})
+			}
+			// blocks and if expressions are evaluated at
+			// runtime even if their branches are constants
+			AnnotatedExpression::Block(_)
+			| AnnotatedExpression::If(_) => not_constant,
+			// lists and maps are never numeric constants
+			AnnotatedExpression::List(_)
+			| AnnotatedExpression::Map(_)
+			| AnnotatedExpression::Range(_)
+			| AnnotatedExpression::Slice(_) => not_constant,
+		}
+	}
+
+	/// Evaluate an integer expression of any integer data
+	/// type
+	///
+	/// # Arguments
+	///
+	/// * `expression` - The annotated expression
+	/// * `platform` - The target machine
+	///
+	/// # Returns
+	///
+	/// The value of the expression if it is an integer
+	/// literal of any data type, an untyped constant
+	/// expression, a typed arithmetic operation on such
+	/// values, or a block or an if expression whose value
+	/// can be evaluated, otherwise `None`
+	pub fn evaluate_integer(
+		expression: &AnnotatedExpression,
+		platform: &Target,
+	) -> Option<BigInt>
+	{
+		match expression
+		{
+			AnnotatedExpression::Literal(literal) =>
+			{
+				match &literal.inner.value
+				{
+					ast::Value::UInt8(value) => Some((*value).into()),
+					ast::Value::UInt16(value) =>
+					{
+						Some((*value).into())
+					}
+					ast::Value::UInt32(value) =>
+					{
+						Some((*value).into())
+					}
+					ast::Value::UInt64(value) =>
+					{
+						Some((*value).into())
+					}
+					ast::Value::UInt128(value) =>
+					{
+						Some((*value).into())
+					}
+					ast::Value::UInt(value) => Some((*value).into()),
+					ast::Value::Int8(value) => Some((*value).into()),
+					ast::Value::Int16(value) => Some((*value).into()),
+					ast::Value::Int32(value) => Some((*value).into()),
+					ast::Value::Int64(value) => Some((*value).into()),
+					ast::Value::Int128(value) =>
+					{
+						Some((*value).into())
+					}
+					ast::Value::Int(value) => Some((*value).into()),
+					ast::Value::BigInt(value) => Some(value.clone()),
+					ast::Value::UntypedInt(value) =>
+					{
+						Some((*value).into())
+					}
+					_ => None,
+				}
+			}
+			AnnotatedExpression::Group(group) =>
+			{
+				Constant::evaluate_integer(
+					&group.inner.expression,
+					platform,
+				)
+			}
+			AnnotatedExpression::Block(block)
+				if block.inner.statements.is_empty() =>
+			{
+				Constant::evaluate_integer(
+					&block.inner.expression,
+					platform,
+				)
+			}
+			AnnotatedExpression::If(if_expr) =>
+			{
+				let condition = Constant::evaluate_bool(
+					&if_expr.inner.condition,
+				)?;
+				Constant::evaluate_integer(
+					ternary!(
+						condition,
+						&if_expr.inner.then_branch,
+						&if_expr.inner.else_branch
+					),
+					platform,
+				)
+			}
+			AnnotatedExpression::Unary(unary)
+				if unary.data_type.is_generic_integer()
+					|| unary.data_type.is_big_integer() =>
+			{
+				let right = Constant::evaluate_integer(
+					&unary.inner.right,
+					platform,
+				)?;
+				let value = match unary.inner.operator
+				{
+					ast::UnaryOperator::Negate => -right,
+				};
+				Constant::apply_overflow_policy(
+					value,
+					&unary.data_type,
+					unary.overflow_policy,
+					platform,
+				)
+			}
+			AnnotatedExpression::Binary(binary)
+				if binary.data_type.is_generic_integer()
+					|| binary.data_type.is_big_integer() =>
+			{
+				let left = Constant::evaluate_integer(
+					&binary.inner.left,
+					platform,
+				)?;
+				let right = Constant::evaluate_integer(
+					&binary.inner.right,
+					platform,
+				)?;
+				// division by zero and negative exponents fail
+				// when the program runs
+				let Ok(Constant::Int(value)) =
+					Constant::evaluate_binary(
+						&binary.inner.operator,
+						&Constant::Int(left),
+						&Constant::Int(right),
+					)
+				else
+				{
+					return None;
+				};
+				Constant::apply_overflow_policy(
+					value,
+					&binary.data_type,
+					binary.overflow_policy,
+					platform,
+				)
+			}
+			expression => match Constant::evaluate(expression)
+			{
+				Some(Constant::Int(value)) => Some(value),
+				_ => None,
+			},
+		}
+	}
+
+	/// Evaluate a map key
+	///
+	/// # Arguments
+	///
+	/// * `expression` - The annotated key expression
+	/// * `platform` - The target machine
+	///
+	/// # Returns
+	///
+	/// The value of the key if it can be evaluated with
+	/// `evaluate_integer` or `evaluate_bool`, otherwise
+	/// `None`
+	pub fn evaluate_key(
+		expression: &AnnotatedExpression,
+		platform: &Target,
+	) -> Option<ConstantKey>
+	{
+		match Constant::evaluate_integer(expression, platform)
+		{
+			Some(value) => Some(ConstantKey::Int(value)),
+			None => Constant::evaluate_bool(expression)
+				.map(ConstantKey::Bool),
+		}
+	}
+
+	/// Evaluate the number of elements of a list expression
+	///
+	/// # Arguments
+	///
+	/// * `expression` - The annotated list or slice
+	///   expression
+	/// * `platform` - The target machine
+	///
+	/// # Returns
+	///
+	/// The length of list literals and of slices with
+	/// constant bounds, also inside of blocks and if
+	/// expressions whose value can be evaluated, otherwise
+	/// `None`
+	pub fn evaluate_length(
+		expression: &AnnotatedExpression,
+		platform: &Target,
+	) -> Option<usize>
+	{
+		match expression
+		{
+			AnnotatedExpression::List(list) =>
+			{
+				Some(list.inner.elements.len())
+			}
+			AnnotatedExpression::Group(group) =>
+			{
+				Constant::evaluate_length(
+					&group.inner.expression,
+					platform,
+				)
+			}
+			AnnotatedExpression::Block(block)
+				if block.inner.statements.is_empty() =>
+			{
+				Constant::evaluate_length(
+					&block.inner.expression,
+					platform,
+				)
+			}
+			AnnotatedExpression::If(if_expr) =>
+			{
+				let condition = Constant::evaluate_bool(
+					&if_expr.inner.condition,
+				)?;
+				Constant::evaluate_length(
+					ternary!(
+						condition,
+						&if_expr.inner.then_branch,
+						&if_expr.inner.else_branch
+					),
+					platform,
+				)
+			}
+			AnnotatedExpression::Slice(slice) =>
+			{
+				let (start, end) = Constant::evaluate_range(
+					&slice.inner.range,
+					platform,
+				)?;
+				(end - start).to_usize()
+			}
+			_ => None,
+		}
+	}
+
+	/// Evaluate the bounds of a range expression
+	///
+	/// # Arguments
+	///
+	/// * `expression` - The annotated range expression
+	/// * `platform` - The target machine
+	///
+	/// # Returns
+	///
+	/// The start and end of the range if both can be
+	/// evaluated with `evaluate_integer`, otherwise `None`.
+	/// The bounds are not limited to any width, so a bound
+	/// too large for an index is past the end of any list.
+	pub fn evaluate_range(
+		expression: &AnnotatedExpression,
+		platform: &Target,
+	) -> Option<(BigInt, BigInt)>
+	{
+		match expression
+		{
+			AnnotatedExpression::Range(range) => Some((
+				Constant::evaluate_integer(
+					&range.inner.start,
+					platform,
+				)?,
+				Constant::evaluate_integer(
+					&range.inner.end,
+					platform,
+				)?,
+			)),
+			AnnotatedExpression::Group(group) =>
+			{
+				Constant::evaluate_range(
+					&group.inner.expression,
+					platform,
+				)
+			}
+			_ => None,
+		}
+	}
+
+	/// Evaluate a boolean expression
+	///
+	/// # Arguments
+	///
+	/// * `expression` - The annotated expression
+	///
+	/// # Returns
+	///
+	/// The value of the expression if it only consists of
+	/// boolean literals and if expressions whose conditions
+	/// can be evaluated, otherwise `None`
+	pub fn evaluate_bool(
+		expression: &AnnotatedExpression,
+	) -> Option<bool>
+	{
+		match expression
+		{
+			AnnotatedExpression::Literal(literal) =>
+			{
+				match literal.inner.value
+				{
+					ast::Value::Bool(value) => Some(value),
+					_ => None,
+				}
+			}
+			AnnotatedExpression::Group(group) =>
+			{
+				Constant::evaluate_bool(&group.inner.expression)
+			}
+			AnnotatedExpression::Block(block)
+				if block.inner.statements.is_empty() =>
+			{
+				Constant::evaluate_bool(&block.inner.expression)
+			}
+			AnnotatedExpression::If(if_expr) =>
+			{
+				let condition = Constant::evaluate_bool(
+					&if_expr.inner.condition,
+				)?;
+				Constant::evaluate_bool(ternary!(
+					condition,
+					&if_expr.inner.then_branch,
+					&if_expr.inner.else_branch
+				))
+			}
+			_ => None,
+		}
+	}
+
+	/// Evaluate a binary operation on two constants
+	///
+	/// # Arguments
+	///
+	/// * `operator` - The binary operator
+	/// * `left` - The left constant
+	/// * `right` - The right constant
+	///
+	/// # Returns
+	///
+	/// The result of the operation. If any of the constants
+	/// is a float, the result is a float.
+	///
+	/// # Errors
+	///
+	/// If the operation divides by zero, raises an integer to
+	/// a negative power or its finite operands produce a
+	/// value that is not finite or has more than
+	/// `MAX_INT_BITS` bits, it will return the error code
+	fn evaluate_binary(
+		operator: &ast::BinaryOperator,
+		left: &Constant,
+		right: &Constant,
+	) -> Result<Constant, ConstantErrorCode>
+	{
+		let is_division = matches!(
+			operator,
+			ast::BinaryOperator::Divide
+				| ast::BinaryOperator::Modulo
+		);
+		if is_division && right.is_zero()
+		{
+			return Err(ConstantErrorCode::DivisionByZero);
+		}
+
+		if let (Constant::Int(left), Constant::Int(right)) =
+			(left, right)
+		{
+			let value = match operator
+			{
+				ast::BinaryOperator::Add => left + right,
+				ast::BinaryOperator::Subtract => left - right,
+				ast::BinaryOperator::Multiply => left * right,
+				// both round toward zero like the integer
+				// operations when the program runs
+				ast::BinaryOperator::Divide => left / right,
+				ast::BinaryOperator::Modulo => left % right,
+				ast::BinaryOperator::Exponent
+					if right.is_negative() =>
+				{
+					return Err(ConstantErrorCode::NegativeExponent);
+				}
+				ast::BinaryOperator::Exponent =>
+				{
+					// the result has about as many bits as the
+					// left operand times the exponent, only 0, 1
+					// and -1 stay small for any exponent
+					let exponent = right
+						.to_u64()
+						.filter(|exponent| {
+							left.bits() <= 1
+								|| left.bits().saturating_mul(*exponent)
+									<= MAX_INT_BITS
+						})
+						.ok_or(ConstantErrorCode::Overflow)?;
+					Pow::pow(left, exponent)
+				}
+			};
+
+			return ternary!(
+				value.bits() <= MAX_INT_BITS,
+				Ok(Constant::Int(value)),
+				Err(ConstantErrorCode::Overflow)
+			);
+		}
+
+		let left =
+			left.to_f64().ok_or(ConstantErrorCode::Overflow)?;
+		let right =
+			right.to_f64().ok_or(ConstantErrorCode::Overflow)?;
+		let value = match operator
+		{
+			ast::BinaryOperator::Add => left + right,
+			ast::BinaryOperator::Subtract => left - right,
+			ast::BinaryOperator::Multiply => left * right,
+			ast::BinaryOperator::Divide => left / right,
+			ast::BinaryOperator::Modulo => left % right,
+			ast::BinaryOperator::Exponent => left.powf(right),
+		};
+
+		// `inf` and `nan` operands may produce `inf` and `nan`
+		ternary!(
+			value.is_finite()
+				|| !left.is_finite()
+				|| !right.is_finite(),
+			Ok(Constant::Float(value)),
+			Err(ConstantErrorCode::Overflow)
+		)
+	}
+
+	/// Convert the constant to a 64-bit float
+	///
+	/// # Returns
+	///
+	/// The nearest double, or `None` if the constant is an
+	/// integer too large for a double
+	fn to_f64(&self) -> Option<f64>
+	{
+		match self
+		{
+			Constant::Int(value) =>
+			{
+				value.to_f64().filter(|value| value.is_finite())
+			}
+			Constant::Float(value) => Some(*value),
+		}
+	}
+
+	/// Check if the constant is zero
+	fn is_zero(&self) -> bool
+	{
+		match self
+		{
+			Constant::Int(value) => value.is_zero(),
+			Constant::Float(value) => *value == 0.0,
+		}
+	}
+
+	/// Check if the constant can be represented in the data
+	/// type without losing its value
+	///
+	/// # Arguments
+	///
+	/// * `data_type` - The target data type
+	/// * `platform` - The target machine
+	///
+	/// # Returns
+	///
+	/// `true` if the constant fits in the data type,
+	/// otherwise `false`
+	pub fn fits_in(
+		&self,
+		data_type: &DataType,
+		platform: &Target,
+	) -> bool
+	{
+		if data_type.is_generic_integer()
+		{
+			return match self
+			{
+				Constant::Int(value) =>
+				{
+					Constant::int_fits_in(value, data_type, platform)
+				}
+				// floats fit only if they have no fractional part
+				Constant::Float(value) =>
+				{
+					value.fract() == 0.0
+						&& BigInt::from_f64(*value).is_some_and(
+							|value| {
+								Constant::int_fits_in(
+									&value, data_type, platform,
+								)
+							},
+						)
+				}
+			};
+		}
+
+		// `inf` and `nan` exist in every floating point data
+		// type, but finite values must not become infinite
+		let value = self.to_f64();
+		match data_type
+		{
+			DataType::Known(KnownDataType::Float16) => value
+				.is_some_and(|value| {
+					!value.is_finite() || (value as f16).is_finite()
+				}),
+			DataType::Known(KnownDataType::Float32) => value
+				.is_some_and(|value| {
+					!value.is_finite() || (value as f32).is_finite()
+				}),
+			DataType::Known(KnownDataType::Double)
+			| DataType::Known(KnownDataType::UntypedFloat) =>
+			{
+				value.is_some()
+			}
+			// bigints hold any integer, so floats fit only if
+			// they have no fractional part
+			DataType::Known(KnownDataType::BigInt) => match self
+			{
+				Constant::Int(_) => true,
+				Constant::Float(value) =>
+				{
+					value.is_finite() && value.fract() == 0.0
+				}
+			},
+			DataType::Known(KnownDataType::UntypedInt) =>
+			{
+				matches!(self, Constant::Int(_))
+			}
+			_ => false,
+		}
+	}
+
+	/// Check if the integer is in the range of the integer
+	/// data type
+	///
+	/// # Arguments
+	///
+	/// * `value` - The integer value
+	/// * `data_type` - The integer data type
+	/// * `platform` - The target machine
+	///
+	/// # Returns
+	///
+	/// `true` if the integer is in range, otherwise `false`
+	fn int_fits_in(
+		value: &BigInt,
+		data_type: &DataType,
+		platform: &Target,
+	) -> bool
+	{
+		let bit_size =
+			u64::from(data_type.get_bit_size(platform));
+
+		if data_type.is_unsigned_integer()
+		{
+			!value.is_negative() && value.bits() <= bit_size
+		}
+		else
+		{
+			// the minimum needs one more bit than the maximum
+			value.bits() < bit_size
+				|| *value == -(BigInt::from(1) << (bit_size - 1))
+		}
+	}
+
+	/// Get the value an integer operation produces when the
+	/// program runs
+	///
+	/// # Arguments
+	///
+	/// * `value` - The exact result of the operation
+	/// * `data_type` - The integer data type of the operation
+	/// * `overflow_policy` - The overflow policy of the
+	///   operation
+	/// * `platform` - The target machine
+	///
+	/// # Returns
+	///
+	/// The value wrapped or clamped to the data type, or
+	/// `None` if the operation traps when the program runs
+	fn apply_overflow_policy(
+		value: BigInt,
+		data_type: &DataType,
+		overflow_policy: Option<OverflowPolicy>,
+		platform: &Target,
+	) -> Option<BigInt>
+	{
+		// bigints never overflow
+		if data_type.is_big_integer()
+			|| Constant::int_fits_in(&value, data_type, platform)
+		{
+			return Some(value);
+		}
+
+		let bit_size = data_type.get_bit_size(platform);
+		let modulus = BigInt::from(1) << bit_size;
+		let min = ternary!(
+			data_type.is_unsigned_integer(),
+			BigInt::zero(),
+			-(BigInt::from(1) << (bit_size - 1))
+		);
+		let max = &min + &modulus - 1;
+
+		match overflow_policy?
+		{
+			OverflowPolicy::Checked => None,
+			OverflowPolicy::Wrapping =>
+			{
+				let mut offset = (value - &min) % &modulus;
+				if offset.is_negative()
+				{
+					offset += &modulus;
+				}
+				Some(offset + min)
+			}
+			OverflowPolicy::Saturating =>
+			{
+				Some(ternary!(value < min, min, max))
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	/// Create an untyped integer constant
+	fn int(value: i128) -> Constant
+	{
+		Constant::Int(value.into())
+	}
+
+	#[test]
+	fn test_constant_display()
+	{
+		assert_eq!(int(-300).to_string(), "-300");
+		assert_eq!(Constant::Float(1.0).to_string(), "1.0");
+		assert_eq!(Constant::Float(0.25).to_string(), "0.25");
+		assert_eq!(
+			Constant::Float(f64::NAN).to_string(),
+			"nan"
+		);
+	}
+
+	#[test]
+	fn test_constant_evaluate_binary()
+	{
+		assert_eq!(
+			Constant::evaluate_binary(
+				&ast::BinaryOperator::Add,
+				&int(200),
+				&int(100)
+			),
+			Ok(int(300))
+		);
+		assert_eq!(
+			Constant::evaluate_binary(
+				&ast::BinaryOperator::Divide,
+				&int(7),
+				&int(2)
+			),
+			Ok(int(3))
+		);
+		assert_eq!(
+			Constant::evaluate_binary(
+				&ast::BinaryOperator::Divide,
+				&int(7),
+				&Constant::Float(2.0)
+			),
+			Ok(Constant::Float(3.5))
+		);
+		assert_eq!(
+			Constant::evaluate_binary(
+				&ast::BinaryOperator::Divide,
+				&int(1),
+				&int(0)
+			),
+			Err(ConstantErrorCode::DivisionByZero)
+		);
+		assert_eq!(
+			Constant::evaluate_binary(
+				&ast::BinaryOperator::Exponent,
+				&int(2),
+				&int(-1)
+			),
+			Err(ConstantErrorCode::NegativeExponent)
+		);
+		assert_eq!(
+			Constant::evaluate_binary(
+				&ast::BinaryOperator::Exponent,
+				&int(10),
+				&int(40)
+			),
+			Ok(Constant::Int(BigInt::from(10).pow(40_u32)))
+		);
+		assert_eq!(
+			Constant::evaluate_binary(
+				&ast::BinaryOperator::Exponent,
+				&int(10),
+				&int(100000)
+			),
+			Err(ConstantErrorCode::Overflow)
+		);
+		assert_eq!(
+			Constant::evaluate_binary(
+				&ast::BinaryOperator::Exponent,
+				&int(-1),
+				&int(100001)
+			),
+			Ok(int(-1))
+		);
+		assert_eq!(
+			Constant::evaluate_binary(
+				&ast::BinaryOperator::Add,
+				&Constant::Int(u128::MAX.into()),
+				&int(1)
+			),
+			Ok(Constant::Int(BigInt::from(u128::MAX) + 1))
+		);
+		// division rounds toward zero
+		assert_eq!(
+			Constant::evaluate_binary(
+				&ast::BinaryOperator::Modulo,
+				&int(-7),
+				&int(2)
+			),
+			Ok(int(-1))
+		);
+		assert_eq!(
+			Constant::evaluate_binary(
+				&ast::BinaryOperator::Multiply,
+				&Constant::Int(BigInt::from(1) << 2000),
+				&Constant::Float(1.0)
+			),
+			Err(ConstantErrorCode::Overflow)
+		);
+		assert_eq!(
+			Constant::evaluate_binary(
+				&ast::BinaryOperator::Modulo,
+				&Constant::Float(1.5),
+				&Constant::Float(0.0)
+			),
+			Err(ConstantErrorCode::DivisionByZero)
+		);
+		assert_eq!(
+			Constant::evaluate_binary(
+				&ast::BinaryOperator::Multiply,
+				&Constant::Float(1e300),
+				&Constant::Float(1e300)
+			),
+			Err(ConstantErrorCode::Overflow)
+		);
+		assert_eq!(
+			Constant::evaluate_binary(
+				&ast::BinaryOperator::Add,
+				&Constant::Float(f64::INFINITY),
+				&int(1)
+			),
+			Ok(Constant::Float(f64::INFINITY))
+		);
+	}
+
+	#[test]
+	fn test_constant_fits_in()
+	{
+		let target = Target::from("i686".to_owned());
+		let uint8 = DataType::Known(KnownDataType::UInt8);
+		let int8 = DataType::Known(KnownDataType::Int8);
+
+		assert!(int(255).fits_in(&uint8, &target));
+		assert!(!int(300).fits_in(&uint8, &target));
+		assert!(!int(-1).fits_in(&uint8, &target));
+		assert!(int(-128).fits_in(&int8, &target));
+		assert!(!int(128).fits_in(&int8, &target));
+		assert!(Constant::Float(2.0).fits_in(&uint8, &target));
+		assert!(!Constant::Float(2.5).fits_in(&uint8, &target));
+		assert!(int(i128::MAX).fits_in(
+			&DataType::Known(KnownDataType::UInt128),
+			&target
+		));
+		assert!(!int(70000).fits_in(
+			&DataType::Known(KnownDataType::Float16),
+			&target
+		));
+		assert!(Constant::Float(1e300).fits_in(
+			&DataType::Known(KnownDataType::Double),
+			&target
+		));
+		assert!(!Constant::Float(1e300).fits_in(
+			&DataType::Known(KnownDataType::Float32),
+			&target
+		));
+		assert!(!Constant::Float(1.5).fits_in(
+			&DataType::Known(KnownDataType::Bool),
+			&target
+		));
+
+		let float32 = DataType::Known(KnownDataType::Float32);
+		assert!(
+			Constant::Float(f64::INFINITY)
+				.fits_in(&float32, &target)
+		);
+		assert!(
+			Constant::Float(f64::NAN).fits_in(&float32, &target)
+		);
+		assert!(
+			!Constant::Float(f64::NAN).fits_in(&int8, &target)
+		);
+
+		let bigint = DataType::Known(KnownDataType::BigInt);
+		assert!(int(i128::MIN).fits_in(&bigint, &target));
+		assert!(
+			Constant::Float(1e300).fits_in(&bigint, &target)
+		);
+		assert!(
+			!Constant::Float(2.5).fits_in(&bigint, &target)
+		);
+		assert!(
+			!Constant::Float(f64::INFINITY)
+				.fits_in(&bigint, &target)
+		);
+
+		let platform_int = DataType::Known(KnownDataType::Int);
+		assert!(!int(1 << 40).fits_in(&platform_int, &target));
+		assert!(int(1 << 40).fits_in(
+			&platform_int,
+			&target.with_pointer_width(64)
+		));
+
+		// untyped integers are exact, so the whole range of
+		// 128-bit integers is available
+		let uint128 = DataType::Known(KnownDataType::UInt128);
+		let int128 = DataType::Known(KnownDataType::Int128);
+		let uint128_max = Constant::Int(u128::MAX.into());
+		assert!(uint128_max.fits_in(&uint128, &target));
+		assert!(!uint128_max.fits_in(&int128, &target));
+		assert!(
+			!Constant::Int(BigInt::from(u128::MAX) + 1)
+				.fits_in(&uint128, &target)
+		);
+		assert!(int(i128::MIN).fits_in(&int128, &target));
+		assert!(
+			!Constant::Int(BigInt::from(i128::MIN) - 1)
+				.fits_in(&int128, &target)
+		);
+		assert!(
+			!Constant::Int(BigInt::from(1) << 2000).fits_in(
+				&DataType::Known(KnownDataType::Double),
+				&target
+			)
+		);
+	}
+}
@@ -1,7 +1,8 @@
 use serde_json::json;
 use smol_str::SmolStr;
 
-use crate::t;
+use crate::common::target::Target;
+use crate::{t, ternary};
 
 /// Known data types
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -23,14 +24,28 @@ pub enum KnownDataType
 	Int32,
 	/// Signed 64-bit integer
 	Int64,
+	/// Unsigned 128-bit integer
+	UInt128,
+	/// Signed 128-bit integer
+	Int128,
+	/// Arbitrary-precision signed integer
+	BigInt,
 	/// Platform-dependent integer
 	Int,
 	/// Platform-dependent unsigned integer
 	UInt,
+	/// 16-bit floating point
+	Float16,
 	/// 32-bit floating point
 	Float32,
 	/// 64-bit floating point
 	Double,
+	/// Boolean
+	Bool,
+	/// Integer constant without a data type yet
+	UntypedInt,
+	/// Floating point constant without a data type yet
+	UntypedFloat,
 }
 
 /// Data type inner
@@ -39,6 +54,15 @@ pub enum DataType
 {
 	/// Known data type
 	Known(KnownDataType),
+	/// Growable list of elements of the same data type
+	List(Box<DataType>),
+	/// Hash map from keys to values, iterated in insertion
+	/// order
+	Map(Box<DataType>, Box<DataType>),
+	/// Half-open range of integers
+	Range(Box<DataType>),
+	/// View of a part of a list, without copying it
+	Slice(Box<DataType>),
 	/// User-defined data type
 	UserDefined(SmolStr),
 }
@@ -57,15 +81,48 @@ impl ToString for DataType
 				KnownDataType::UInt16 => "uint16".to_string(),
 				KnownDataType::UInt32 => "uint32".to_string(),
 				KnownDataType::UInt64 => "uint64".to_string(),
-				KnownDataType::Int8 => "uint8".to_string(),
+				KnownDataType::Int8 => "int8".to_string(),
 				KnownDataType::Int16 => "int16".to_string(),
 				KnownDataType::Int32 => "int32".to_string(),
 				KnownDataType::Int64 => "int64".to_string(),
+				KnownDataType::UInt128 => "uint128".to_string(),
+				KnownDataType::Int128 => "int128".to_string(),
+				KnownDataType::BigInt => "bigint".to_string(),
 				KnownDataType::Int => "int".to_string(),
-				KnownDataType::UInt => "uInt".to_string(),
+				KnownDataType::UInt => "uint".to_string(),
+				KnownDataType::Float16 => "float16".to_string(),
 				KnownDataType::Float32 => "float32".to_string(),
 				KnownDataType::Double => "double".to_string(),
+				KnownDataType::Bool => "bool".to_string(),
+				KnownDataType::UntypedInt =>
+				{
+					"untyped int".to_string()
+				}
+				KnownDataType::UntypedFloat =>
+				{
+					"untyped float".to_string()
+				}
 			},
+			DataType::List(element) =>
+			{
+				format!("List[{}]", element.to_string())
+			}
+			DataType::Map(key, value) =>
+			{
+				format!(
+					"Map[{}, {}]",
+					key.to_string(),
+					value.to_string()
+				)
+			}
+			DataType::Range(element) =>
+			{
+				format!("Range[{}]", element.to_string())
+			}
+			DataType::Slice(element) =>
+			{
+				format!("Slice[{}]", element.to_string())
+			}
 			DataType::UserDefined(user_defined) =>
 			{
 				user_defined.to_string()
@@ -97,16 +154,62 @@ impl DataType
 			DataType::Known(KnownDataType::Int16) => "int16",
 			DataType::Known(KnownDataType::Int32) => "int32",
 			DataType::Known(KnownDataType::Int64) => "int64",
+			DataType::Known(KnownDataType::UInt128) => "uint128",
+			DataType::Known(KnownDataType::Int128) => "int128",
+			DataType::Known(KnownDataType::BigInt) => "bigint",
 			DataType::Known(KnownDataType::Int) => "int",
 			DataType::Known(KnownDataType::UInt) => "uint",
+			DataType::Known(KnownDataType::Float16) => "float16",
 			DataType::Known(KnownDataType::Float32) => "float32",
 			DataType::Known(KnownDataType::Double) => "double",
+			DataType::Known(KnownDataType::Bool) => "bool",
+			DataType::Known(KnownDataType::UntypedInt) =>
+			{
+				"untyped-int"
+			}
+			DataType::Known(KnownDataType::UntypedFloat) =>
+			{
+				"untyped-float"
+			}
+			DataType::List(_) => "list",
+			DataType::Map(..) => "map",
+			DataType::Range(_) => "range",
+			DataType::Slice(_) => "slice",
 			DataType::UserDefined(t) => t.as_str(),
 		};
 
+		// lists, maps, ranges and slices are described by the
+		// data types of their elements
+		let element_type =
+			match self
+			{
+				DataType::List(element)
+				| DataType::Range(element)
+				| DataType::Slice(element) => Some(
+					element.description(2, "lowercase", None, false),
+				),
+				_ => None,
+			};
+		let (key_type, value_type) = match self
+		{
+			DataType::Map(key, value) => (
+				Some(key.description(2, "lowercase", None, false)),
+				Some(value.description(
+					2,
+					"lowercase",
+					None,
+					false,
+				)),
+			),
+			_ => (None, None),
+		};
+
 		t!(
 			format!("data-type-description-{key}"),
 			value = value.clone().map(|v| json!(v).to_string()),
+			element_type = element_type,
+			key_type = key_type,
+			value_type = value_type,
 			capitalization = capitalization,
 			count = count,
 			show_count = show_count.to_string(),
@@ -133,6 +236,33 @@ impl DataType
 	/// `false`
 	pub fn is_same(lhs: &DataType, rhs: &DataType) -> bool
 	{
+		match (lhs, rhs)
+		{
+			(
+				DataType::List(lhs_element),
+				DataType::List(rhs_element),
+			)
+			| (
+				DataType::Range(lhs_element),
+				DataType::Range(rhs_element),
+			)
+			| (
+				DataType::Slice(lhs_element),
+				DataType::Slice(rhs_element),
+			) =>
+			{
+				return DataType::is_same(lhs_element, rhs_element);
+			}
+			_ => (),
+		}
+		if let (
+			DataType::Map(lhs_key, lhs_value),
+			DataType::Map(rhs_key, rhs_value),
+		) = (lhs, rhs)
+		{
+			return DataType::is_same(lhs_key, rhs_key)
+				&& DataType::is_same(lhs_value, rhs_value);
+		}
 		if let DataType::Known(lhs_known) = lhs
 			&& let DataType::Known(rhs_known) = rhs
 		{
@@ -145,10 +275,14 @@ impl DataType
 	/// Get the bit size of the data type
 	/// in bits
 	///
+	/// # Arguments
+	///
+	/// * `platform` - The target machine
+	///
 	/// # Returns
 	///
 	/// The bit size of the data type
-	pub fn get_bit_size(&self) -> u32
+	pub fn get_bit_size(&self, platform: &Target) -> u32
 	{
 		match self
 		{
@@ -160,14 +294,71 @@ impl DataType
 			DataType::Known(KnownDataType::Int16) => 16,
 			DataType::Known(KnownDataType::Int32) => 32,
 			DataType::Known(KnownDataType::Int64) => 64,
-			DataType::Known(KnownDataType::Int) => 32, /* it can be 64 but we are using 32 to be safe */
-			DataType::Known(KnownDataType::UInt) => 32, /* it can be 64 but we are using 32 to be safe */
+			DataType::Known(KnownDataType::UInt128) => 128,
+			DataType::Known(KnownDataType::Int128) => 128,
+			// bigints grow as wide as their value needs
+			DataType::Known(KnownDataType::BigInt) => 0,
+			// `int` and `uint` are as wide as a pointer
+			DataType::Known(KnownDataType::Int) =>
+			{
+				platform.pointer_width
+			}
+			DataType::Known(KnownDataType::UInt) =>
+			{
+				platform.pointer_width
+			}
+			DataType::Known(KnownDataType::Float16) => 16,
 			DataType::Known(KnownDataType::Float32) => 32,
 			DataType::Known(KnownDataType::Double) => 64,
+			DataType::Known(KnownDataType::Bool) => 1,
+			// untyped constants have no size until they are
+			// converted to the data type of their context
+			DataType::Known(KnownDataType::UntypedInt) => 0,
+			DataType::Known(KnownDataType::UntypedFloat) => 0,
+			// lists and maps grow on the heap, so they have no
+			// fixed size
+			DataType::List(_) => 0,
+			DataType::Map(..) => 0,
+			// a range holds its start and end
+			DataType::Range(element) =>
+			{
+				2 * element.get_bit_size(platform)
+			}
+			// a slice holds a pointer into the list and a length
+			DataType::Slice(_) => 2 * platform.pointer_width,
 			DataType::UserDefined(_) => 0,
 		}
 	}
 
+	/// Get the precision and the maximum exponent of a
+	/// floating point data type
+	///
+	/// # Returns
+	///
+	/// The number of significant bits, including the
+	/// implicit leading bit, and the largest binary exponent
+	/// of finite values, or `None` if the data type is not a
+	/// floating point
+	fn get_float_format(&self) -> Option<(u32, u32)>
+	{
+		match self
+		{
+			DataType::Known(KnownDataType::Float16) =>
+			{
+				Some((11, 15))
+			}
+			DataType::Known(KnownDataType::Float32) =>
+			{
+				Some((24, 127))
+			}
+			DataType::Known(KnownDataType::Double) =>
+			{
+				Some((53, 1023))
+			}
+			_ => None,
+		}
+	}
+
 	/// Check if the source data type can be implictly
 	/// converted to target data type
 	///
@@ -175,6 +366,7 @@ impl DataType
 	///
 	/// * `source` - The source data type
 	/// * `target` - The target data type
+	/// * `platform` - The target machine
 	///
 	/// # Returns
 	///
@@ -183,26 +375,95 @@ impl DataType
 	pub fn can_implictly_cast_to(
 		source: &DataType,
 		target: &DataType,
+		platform: &Target,
 	) -> bool
 	{
-		let source_bit_size = source.get_bit_size();
+		// lists, maps and ranges of untyped constants take the
+		// element data types of their context, other elements
+		// must match
+		match (source, target)
+		{
+			(
+				DataType::List(source_element),
+				DataType::List(target_element),
+			)
+			| (
+				DataType::Range(source_element),
+				DataType::Range(target_element),
+			)
+			| (
+				DataType::Slice(source_element),
+				DataType::Slice(target_element),
+			) =>
+			{
+				return DataType::can_convert_element(
+					source_element,
+					target_element,
+					platform,
+				);
+			}
+			(
+				DataType::Map(source_key, source_value),
+				DataType::Map(target_key, target_value),
+			) =>
+			{
+				return DataType::can_convert_element(
+					source_key, target_key, platform,
+				) && DataType::can_convert_element(
+					source_value,
+					target_value,
+					platform,
+				);
+			}
+			_ => (),
+		}
+
+		// untyped constants take the data type of their
+		// context. Whether the value fits is checked later.
+		if source.is_untyped()
+		{
+			let is_float_to_int = source
+				== &DataType::Known(KnownDataType::UntypedFloat)
+				&& target
+					== &DataType::Known(KnownDataType::UntypedInt);
+			return target.is_numeric() && !is_float_to_int;
+		}
+
+		let source_bit_size = source.get_bit_size(platform);
+
+		// integers convert to floats that represent all of
+		// their values exactly. The float needs a significant
+		// bit and an exponent for each magnitude bit, so
+		// float16 takes 8 bit integers, float32 16 bit integers
+		// and double 32 bit integers.
+		let magnitude_bits = ternary!(
+			source.is_signed_integer(),
+			source_bit_size - 1,
+			source_bit_size
+		);
+		let is_integer_to_float = target
+			.get_float_format()
+			.is_some_and(|(precision, max_exponent)| {
+				magnitude_bits <= precision
+					&& magnitude_bits <= max_exponent + 1
+			});
 
 		if source.is_signed_integer()
 		{
 			(target.is_signed_integer()
-				&& target.get_bit_size() >= source_bit_size)
-				|| target.is_floating_point()
+				&& target.get_bit_size(platform) >= source_bit_size)
+				|| is_integer_to_float
 		}
 		else if source.is_unsigned_integer()
 		{
 			(target.is_unsigned_integer()
-				&& target.get_bit_size() >= source_bit_size)
-				|| target.is_floating_point()
+				&& target.get_bit_size(platform) >= source_bit_size)
+				|| is_integer_to_float
 		}
 		else if source.is_floating_point()
 		{
 			target.is_floating_point()
-				&& target.get_bit_size() >= source_bit_size
+				&& target.get_bit_size(platform) >= source_bit_size
 		}
 		else
 		{
@@ -224,6 +485,7 @@ impl DataType
 			| DataType::Known(KnownDataType::Int16)
 			| DataType::Known(KnownDataType::Int32)
 			| DataType::Known(KnownDataType::Int64)
+			| DataType::Known(KnownDataType::Int128)
 			| DataType::Known(KnownDataType::Int) => true,
 			_ => false,
 		}
@@ -243,6 +505,7 @@ impl DataType
 			| DataType::Known(KnownDataType::UInt16)
 			| DataType::Known(KnownDataType::UInt32)
 			| DataType::Known(KnownDataType::UInt64)
+			| DataType::Known(KnownDataType::UInt128)
 			| DataType::Known(KnownDataType::UInt) => true,
 			_ => false,
 		}
@@ -260,6 +523,35 @@ impl DataType
 			|| self.is_unsigned_integer();
 	}
 
+	/// Check if the data type is an arbitrary-precision
+	/// integer
+	///
+	/// # Returns
+	///
+	/// `true` if the data type is `bigint`, otherwise
+	/// `false`
+	pub fn is_big_integer(&self) -> bool
+	{
+		matches!(self, DataType::Known(KnownDataType::BigInt))
+	}
+
+	/// Check if the data type can be the start and end of a
+	/// range
+	///
+	/// # Returns
+	///
+	/// `true` if the data type is an integer, otherwise
+	/// `false`
+	pub fn is_range_bound(&self) -> bool
+	{
+		self.is_generic_integer()
+			|| self.is_big_integer()
+			|| matches!(
+				self,
+				DataType::Known(KnownDataType::UntypedInt)
+			)
+	}
+
 	/// Check if the data type is floating point
 	///
 	/// # Returns
@@ -270,12 +562,149 @@ impl DataType
 	{
 		match self
 		{
-			DataType::Known(KnownDataType::Float32)
+			DataType::Known(KnownDataType::Float16)
+			| DataType::Known(KnownDataType::Float32)
 			| DataType::Known(KnownDataType::Double) => true,
 			_ => false,
 		}
 	}
 
+	/// Check if the data type is an untyped constant
+	///
+	/// # Returns
+	///
+	/// `true` if the data type is untyped, otherwise `false`
+	pub fn is_untyped(&self) -> bool
+	{
+		match self
+		{
+			DataType::Known(KnownDataType::UntypedInt)
+			| DataType::Known(KnownDataType::UntypedFloat) => true,
+			_ => false,
+		}
+	}
+
+	/// Check if the data type is a list, a map, a range or a
+	/// slice with untyped constant elements, directly or
+	/// nested
+	///
+	/// # Returns
+	///
+	/// `true` if the data type contains untyped constants,
+	/// otherwise `false`
+	pub fn contains_untyped(&self) -> bool
+	{
+		let is_untyped_element = |element: &DataType| {
+			element.is_untyped() || element.contains_untyped()
+		};
+
+		match self
+		{
+			DataType::List(element)
+			| DataType::Range(element)
+			| DataType::Slice(element) =>
+			{
+				is_untyped_element(element)
+			}
+			DataType::Map(key, value) =>
+			{
+				is_untyped_element(key) || is_untyped_element(value)
+			}
+			_ => false,
+		}
+	}
+
+	/// Get the data type that untyped constants take when
+	/// their context has no data type
+	///
+	/// # Returns
+	///
+	/// `int64` for untyped integers, `double` for untyped
+	/// floats, directly or as elements. Other data types are
+	/// returned as is.
+	pub fn default_data_type(&self) -> DataType
+	{
+		let default_element = |element: &DataType| {
+			Box::new(element.default_data_type())
+		};
+
+		match self
+		{
+			DataType::Known(KnownDataType::UntypedInt) =>
+			{
+				DataType::Known(KnownDataType::Int64)
+			}
+			DataType::Known(KnownDataType::UntypedFloat) =>
+			{
+				DataType::Known(KnownDataType::Double)
+			}
+			DataType::List(element) =>
+			{
+				DataType::List(default_element(element))
+			}
+			DataType::Map(key, value) => DataType::Map(
+				default_element(key),
+				default_element(value),
+			),
+			DataType::Range(element) =>
+			{
+				DataType::Range(default_element(element))
+			}
+			DataType::Slice(element) =>
+			{
+				DataType::Slice(default_element(element))
+			}
+			data_type => data_type.clone(),
+		}
+	}
+
+	/// Check if an element of a list or a map can be
+	/// implicitly converted. Only untyped elements are
+	/// converted, typed elements must be the same.
+	///
+	/// # Arguments
+	///
+	/// * `source` - The source element data type
+	/// * `target` - The target element data type
+	/// * `platform` - The target machine
+	///
+	/// # Returns
+	///
+	/// `true` if the element can be converted, otherwise
+	/// `false`
+	fn can_convert_element(
+		source: &DataType,
+		target: &DataType,
+		platform: &Target,
+	) -> bool
+	{
+		ternary!(
+			source.is_untyped() || source.contains_untyped(),
+			DataType::can_implictly_cast_to(
+				source, target, platform
+			),
+			DataType::is_same(source, target)
+		)
+	}
+
+	/// Check if values of the data type can be used as map
+	/// keys
+	///
+	/// # Returns
+	///
+	/// `true` if the data type is an integer or a boolean,
+	/// otherwise `false`
+	pub fn is_hashable(&self) -> bool
+	{
+		self.is_generic_integer()
+			|| self.is_big_integer()
+			|| matches!(
+				self,
+				DataType::Known(KnownDataType::Bool)
+					| DataType::Known(KnownDataType::UntypedInt)
+			)
+	}
+
 	/// Check if the data type is number
 	///
 	/// # Returns
@@ -285,7 +714,9 @@ impl DataType
 	pub fn is_numeric(&self) -> bool
 	{
 		return self.is_generic_integer()
-			|| self.is_floating_point();
+			|| self.is_big_integer()
+			|| self.is_floating_point()
+			|| self.is_untyped();
 	}
 
 	/// Infer the data type of the binary expression
@@ -295,6 +726,7 @@ impl DataType
 	///
 	/// * `left` - The left data type
 	/// * `right` - The right data type
+	/// * `platform` - The target machine
 	///
 	/// # Returns
 	///
@@ -303,22 +735,74 @@ impl DataType
 	pub fn binary_expr_result_data_type(
 		left: &DataType,
 		right: &DataType,
+		platform: &Target,
 	) -> Option<DataType>
 	{
 		if DataType::is_same(left, right)
 		{
 			return Some(left.clone());
 		}
-		else if DataType::can_implictly_cast_to(right, left)
+		else if DataType::can_implictly_cast_to(
+			right, left, platform,
+		)
 		{
 			return Some(left.clone());
 		}
-		else if DataType::can_implictly_cast_to(left, right)
+		else if DataType::can_implictly_cast_to(
+			left, right, platform,
+		)
 		{
 			return Some(right.clone());
 		}
 		None
 	}
+
+	/// Check if an implicit conversion is allowed on every
+	/// supported target, regardless of the width of `int`
+	/// and `uint`
+	///
+	/// # Arguments
+	///
+	/// * `source` - The source data type
+	/// * `target` - The target data type
+	/// * `platform` - The target machine
+	///
+	/// # Returns
+	///
+	/// `true` if the conversion is allowed on every target
+	/// it is allowed on `platform`, otherwise `false`
+	pub fn is_portable_cast(
+		source: &DataType,
+		target: &DataType,
+		platform: &Target,
+	) -> bool
+	{
+		Target::POINTER_WIDTHS.iter().all(|pointer_width| {
+			DataType::can_implictly_cast_to(
+				source,
+				target,
+				&platform.with_pointer_width(*pointer_width),
+			) || !DataType::can_implictly_cast_to(
+				source, target, platform,
+			)
+		})
+	}
+
+	/// Check if the size of the data type depends on the
+	/// target machine
+	///
+	/// # Returns
+	///
+	/// `true` if the data type is `int` or `uint`, otherwise
+	/// `false`
+	pub fn is_target_dependent(&self) -> bool
+	{
+		matches!(
+			self,
+			DataType::Known(KnownDataType::Int)
+				| DataType::Known(KnownDataType::UInt)
+		)
+	}
 }
 
 #[cfg(test)]
@@ -347,7 +831,7 @@ mod tests
 		);
 		assert_eq!(
 			DataType::Known(KnownDataType::Int8).to_string(),
-			"uint8"
+			"int8"
 		);
 		assert_eq!(
 			DataType::Known(KnownDataType::Int16).to_string(),
@@ -361,13 +845,29 @@ mod tests
 			DataType::Known(KnownDataType::Int64).to_string(),
 			"int64"
 		);
+		assert_eq!(
+			DataType::Known(KnownDataType::UInt128).to_string(),
+			"uint128"
+		);
+		assert_eq!(
+			DataType::Known(KnownDataType::Int128).to_string(),
+			"int128"
+		);
+		assert_eq!(
+			DataType::Known(KnownDataType::BigInt).to_string(),
+			"bigint"
+		);
+		assert_eq!(
+			DataType::Known(KnownDataType::Float16).to_string(),
+			"float16"
+		);
 		assert_eq!(
 			DataType::Known(KnownDataType::Int).to_string(),
 			"int"
 		);
 		assert_eq!(
 			DataType::Known(KnownDataType::UInt).to_string(),
-			"uInt"
+			"uint"
 		);
 		assert_eq!(
 			DataType::Known(KnownDataType::Float32).to_string(),
@@ -377,6 +877,20 @@ mod tests
 			DataType::Known(KnownDataType::Double).to_string(),
 			"double"
 		);
+		assert_eq!(
+			DataType::Known(KnownDataType::Bool).to_string(),
+			"bool"
+		);
+		assert_eq!(
+			DataType::Known(KnownDataType::UntypedInt)
+				.to_string(),
+			"untyped int"
+		);
+		assert_eq!(
+			DataType::Known(KnownDataType::UntypedFloat)
+				.to_string(),
+			"untyped float"
+		);
 		assert_eq!(
 			DataType::UserDefined("MyType".into()).to_string(),
 			"MyType"
@@ -458,6 +972,42 @@ mod tests
 			),
 			"a 64-bit integer"
 		);
+		assert_eq!(
+			DataType::Known(KnownDataType::Int128).description(
+				1,
+				"lowercase",
+				None,
+				true
+			),
+			"a 128-bit integer"
+		);
+		assert_eq!(
+			DataType::Known(KnownDataType::UInt128).description(
+				1,
+				"lowercase",
+				None,
+				true
+			),
+			"a 128-bit unsigned integer"
+		);
+		assert_eq!(
+			DataType::Known(KnownDataType::BigInt).description(
+				1,
+				"lowercase",
+				None,
+				true
+			),
+			"an arbitrary-precision integer"
+		);
+		assert_eq!(
+			DataType::Known(KnownDataType::Float16).description(
+				1,
+				"lowercase",
+				None,
+				true
+			),
+			"a 16-bit floating point number"
+		);
 		assert_eq!(
 			DataType::Known(KnownDataType::Int).description(
 				1,
@@ -494,6 +1044,20 @@ mod tests
 			),
 			"a double precision floating point number"
 		);
+		assert_eq!(
+			DataType::Known(KnownDataType::Bool).description(
+				1,
+				"lowercase",
+				None,
+				true
+			),
+			"a boolean"
+		);
+		assert_eq!(
+			DataType::Known(KnownDataType::UntypedInt)
+				.description(1, "lowercase", None, true),
+			"an untyped integer constant"
+		);
 		assert_eq!(
 			DataType::UserDefined("MyType".into()).description(
 				1,
@@ -543,57 +1107,91 @@ mod tests
 	#[test]
 	fn test_data_type_get_bit_size()
 	{
+		let target = Target::from("i686".to_owned());
+
 		assert_eq!(
-			DataType::Known(KnownDataType::UInt8).get_bit_size(),
+			DataType::Known(KnownDataType::UInt8)
+				.get_bit_size(&target),
 			8
 		);
 		assert_eq!(
-			DataType::Known(KnownDataType::UInt16).get_bit_size(),
+			DataType::Known(KnownDataType::UInt16)
+				.get_bit_size(&target),
 			16
 		);
 		assert_eq!(
-			DataType::Known(KnownDataType::UInt32).get_bit_size(),
+			DataType::Known(KnownDataType::UInt32)
+				.get_bit_size(&target),
 			32
 		);
 		assert_eq!(
-			DataType::Known(KnownDataType::UInt64).get_bit_size(),
+			DataType::Known(KnownDataType::UInt64)
+				.get_bit_size(&target),
 			64
 		);
 		assert_eq!(
-			DataType::Known(KnownDataType::Int8).get_bit_size(),
+			DataType::Known(KnownDataType::Int8)
+				.get_bit_size(&target),
 			8
 		);
 		assert_eq!(
-			DataType::Known(KnownDataType::Int16).get_bit_size(),
+			DataType::Known(KnownDataType::Int16)
+				.get_bit_size(&target),
 			16
 		);
 		assert_eq!(
-			DataType::Known(KnownDataType::Int32).get_bit_size(),
+			DataType::Known(KnownDataType::Int32)
+				.get_bit_size(&target),
 			32
 		);
 		assert_eq!(
-			DataType::Known(KnownDataType::Int64).get_bit_size(),
+			DataType::Known(KnownDataType::Int64)
+				.get_bit_size(&target),
 			64
 		);
 		assert_eq!(
-			DataType::Known(KnownDataType::Int).get_bit_size(),
+			DataType::Known(KnownDataType::UInt128)
+				.get_bit_size(&target),
+			128
+		);
+		assert_eq!(
+			DataType::Known(KnownDataType::Int128)
+				.get_bit_size(&target),
+			128
+		);
+		assert_eq!(
+			DataType::Known(KnownDataType::Float16)
+				.get_bit_size(&target),
+			16
+		);
+		assert_eq!(
+			DataType::Known(KnownDataType::Int)
+				.get_bit_size(&target),
 			32
 		);
 		assert_eq!(
-			DataType::Known(KnownDataType::UInt).get_bit_size(),
+			DataType::Known(KnownDataType::UInt)
+				.get_bit_size(&target),
 			32
 		);
 		assert_eq!(
 			DataType::Known(KnownDataType::Float32)
-				.get_bit_size(),
+				.get_bit_size(&target),
 			32
 		);
 		assert_eq!(
-			DataType::Known(KnownDataType::Double).get_bit_size(),
+			DataType::Known(KnownDataType::Double)
+				.get_bit_size(&target),
 			64
 		);
 		assert_eq!(
-			DataType::UserDefined("MyType".into()).get_bit_size(),
+			DataType::Known(KnownDataType::Bool)
+				.get_bit_size(&target),
+			1
+		);
+		assert_eq!(
+			DataType::UserDefined("MyType".into())
+				.get_bit_size(&target),
 			0
 		);
 	}
@@ -601,92 +1199,291 @@ mod tests
 	#[test]
 	fn test_data_type_can_implictly_cast_to()
 	{
+		let target = Target::from("i686".to_owned());
+
 		assert_eq!(
 			DataType::can_implictly_cast_to(
 				&DataType::Known(KnownDataType::UInt8),
-				&DataType::Known(KnownDataType::UInt8)
+				&DataType::Known(KnownDataType::UInt8),
+				&target
 			),
 			true
 		);
 		assert_eq!(
 			DataType::can_implictly_cast_to(
 				&DataType::Known(KnownDataType::UInt8),
-				&DataType::Known(KnownDataType::UInt32)
+				&DataType::Known(KnownDataType::UInt32),
+				&target
 			),
 			true
 		);
 		assert_eq!(
 			DataType::can_implictly_cast_to(
 				&DataType::Known(KnownDataType::UInt8),
-				&DataType::Known(KnownDataType::Int8)
+				&DataType::Known(KnownDataType::Int8),
+				&target
 			),
 			false
 		);
 		assert_eq!(
 			DataType::can_implictly_cast_to(
 				&DataType::Known(KnownDataType::UInt8),
-				&DataType::Known(KnownDataType::Int16)
+				&DataType::Known(KnownDataType::Int16),
+				&target
 			),
 			false
 		);
 		assert_eq!(
 			DataType::can_implictly_cast_to(
 				&DataType::Known(KnownDataType::UInt8),
-				&DataType::Known(KnownDataType::Int64)
+				&DataType::Known(KnownDataType::Int64),
+				&target
 			),
 			false
 		);
 		assert_eq!(
 			DataType::can_implictly_cast_to(
 				&DataType::Known(KnownDataType::UInt8),
-				&DataType::Known(KnownDataType::Float32)
+				&DataType::Known(KnownDataType::Float32),
+				&target
 			),
 			true
 		);
 		assert_eq!(
 			DataType::can_implictly_cast_to(
 				&DataType::Known(KnownDataType::UInt8),
-				&DataType::Known(KnownDataType::Double)
+				&DataType::Known(KnownDataType::Double),
+				&target
 			),
 			true
 		);
 		assert_eq!(
 			DataType::can_implictly_cast_to(
 				&DataType::Known(KnownDataType::Int),
-				&DataType::Known(KnownDataType::Int64)
+				&DataType::Known(KnownDataType::Int64),
+				&target
 			),
 			true
 		);
 		assert_eq!(
 			DataType::can_implictly_cast_to(
 				&DataType::Known(KnownDataType::Int),
-				&DataType::Known(KnownDataType::Double)
+				&DataType::Known(KnownDataType::Double),
+				&target
 			),
 			true
 		);
 		assert_eq!(
 			DataType::can_implictly_cast_to(
 				&DataType::Known(KnownDataType::Float32),
-				&DataType::Known(KnownDataType::Double)
+				&DataType::Known(KnownDataType::Double),
+				&target
 			),
 			true
 		);
 		assert_eq!(
 			DataType::can_implictly_cast_to(
 				&DataType::Known(KnownDataType::Double),
-				&DataType::Known(KnownDataType::Float32)
+				&DataType::Known(KnownDataType::Float32),
+				&target
+			),
+			false
+		);
+		assert_eq!(
+			DataType::can_implictly_cast_to(
+				&DataType::Known(KnownDataType::Int64),
+				&DataType::Known(KnownDataType::Int128),
+				&target
+			),
+			true
+		);
+		assert_eq!(
+			DataType::can_implictly_cast_to(
+				&DataType::Known(KnownDataType::Int128),
+				&DataType::Known(KnownDataType::Int64),
+				&target
+			),
+			false
+		);
+		assert_eq!(
+			DataType::can_implictly_cast_to(
+				&DataType::Known(KnownDataType::UInt128),
+				&DataType::Known(KnownDataType::Int128),
+				&target
+			),
+			false
+		);
+		assert_eq!(
+			DataType::can_implictly_cast_to(
+				&DataType::Known(KnownDataType::Float16),
+				&DataType::Known(KnownDataType::Float32),
+				&target
+			),
+			true
+		);
+		assert_eq!(
+			DataType::can_implictly_cast_to(
+				&DataType::Known(KnownDataType::Float32),
+				&DataType::Known(KnownDataType::Float16),
+				&target
+			),
+			false
+		);
+		assert_eq!(
+			DataType::can_implictly_cast_to(
+				&DataType::Known(KnownDataType::UInt8),
+				&DataType::Known(KnownDataType::Float16),
+				&target
+			),
+			true
+		);
+		assert_eq!(
+			DataType::can_implictly_cast_to(
+				&DataType::Known(KnownDataType::Int16),
+				&DataType::Known(KnownDataType::Float16),
+				&target
+			),
+			false
+		);
+		assert_eq!(
+			DataType::can_implictly_cast_to(
+				&DataType::Known(KnownDataType::Int),
+				&DataType::Known(KnownDataType::Float16),
+				&target
 			),
 			false
 		);
 		assert_eq!(
 			DataType::can_implictly_cast_to(
 				&DataType::UserDefined("MyType".into()),
-				&DataType::Known(KnownDataType::Float32)
+				&DataType::Known(KnownDataType::Float32),
+				&target
+			),
+			false
+		);
+
+		// integers only convert to floats that hold all of
+		// their values exactly
+		for (source, float, expected) in [
+			(KnownDataType::UInt16, KnownDataType::Float32, true),
+			(KnownDataType::Int32, KnownDataType::Float32, false),
+			(
+				KnownDataType::Int128,
+				KnownDataType::Float32,
+				false,
+			),
+			(
+				KnownDataType::UInt128,
+				KnownDataType::Float32,
+				false,
+			),
+			(KnownDataType::Int32, KnownDataType::Double, true),
+			(KnownDataType::UInt32, KnownDataType::Double, true),
+			(KnownDataType::Int64, KnownDataType::Double, false),
+			(KnownDataType::Int64, KnownDataType::Float16, false),
+			(
+				KnownDataType::UInt64,
+				KnownDataType::Float16,
+				false,
+			),
+		]
+		{
+			assert_eq!(
+				DataType::can_implictly_cast_to(
+					&DataType::Known(source.clone()),
+					&DataType::Known(float.clone()),
+					&target
+				),
+				expected,
+				"{:?} to {:?}",
+				source,
+				float
+			);
+		}
+		assert_eq!(
+			DataType::can_implictly_cast_to(
+				&DataType::Known(KnownDataType::UntypedInt),
+				&DataType::Known(KnownDataType::UInt8),
+				&target
+			),
+			true
+		);
+		assert_eq!(
+			DataType::can_implictly_cast_to(
+				&DataType::Known(KnownDataType::UntypedFloat),
+				&DataType::Known(KnownDataType::Int8),
+				&target
+			),
+			true
+		);
+		assert_eq!(
+			DataType::can_implictly_cast_to(
+				&DataType::Known(KnownDataType::UntypedFloat),
+				&DataType::Known(KnownDataType::UntypedInt),
+				&target
+			),
+			false
+		);
+		assert_eq!(
+			DataType::can_implictly_cast_to(
+				&DataType::Known(KnownDataType::UInt8),
+				&DataType::Known(KnownDataType::UntypedInt),
+				&target
+			),
+			false
+		);
+		assert_eq!(
+			DataType::can_implictly_cast_to(
+				&DataType::Known(KnownDataType::UntypedInt),
+				&DataType::Known(KnownDataType::Bool),
+				&target
 			),
 			false
 		);
 	}
 
+	#[test]
+	fn test_data_type_target_dependent_size()
+	{
+		let target = Target::from("x86_64".to_owned());
+		let int = DataType::Known(KnownDataType::Int);
+		let int32 = DataType::Known(KnownDataType::Int32);
+		let int64 = DataType::Known(KnownDataType::Int64);
+
+		assert_eq!(int.get_bit_size(&target), 64);
+		assert!(DataType::can_implictly_cast_to(
+			&int64, &int, &target
+		));
+		assert!(!DataType::can_implictly_cast_to(
+			&int64,
+			&int,
+			&target.with_pointer_width(32)
+		));
+
+		assert!(int.is_target_dependent());
+		assert!(!int64.is_target_dependent());
+		assert!(!DataType::is_portable_cast(
+			&int64, &int, &target
+		));
+		assert!(DataType::is_portable_cast(
+			&int32, &int, &target
+		));
+		assert!(DataType::is_portable_cast(
+			&int, &int64, &target
+		));
+		assert!(!DataType::is_portable_cast(
+			&int,
+			&int32,
+			&target.with_pointer_width(32)
+		));
+		// conversions that are never allowed are portable
+		assert!(DataType::is_portable_cast(
+			&int,
+			&DataType::Known(KnownDataType::Int16),
+			&target
+		));
+	}
+
 	#[test]
 	fn test_data_type_is_numeric()
 	{
@@ -738,98 +1535,396 @@ mod tests
 			DataType::Known(KnownDataType::Double).is_numeric(),
 			true
 		);
+		assert_eq!(
+			DataType::Known(KnownDataType::Bool).is_numeric(),
+			false
+		);
 		assert_eq!(
 			DataType::UserDefined("MyType".into()).is_numeric(),
 			false
 		);
+		assert_eq!(
+			DataType::Known(KnownDataType::BigInt).is_numeric(),
+			true
+		);
+	}
+
+	#[test]
+	fn test_data_type_default_data_type()
+	{
+		let int64 = DataType::Known(KnownDataType::Int64);
+		let double = DataType::Known(KnownDataType::Double);
+
+		assert_eq!(
+			DataType::Known(KnownDataType::UntypedInt)
+				.default_data_type(),
+			int64
+		);
+		assert_eq!(
+			DataType::Known(KnownDataType::UntypedFloat)
+				.default_data_type(),
+			double
+		);
+		assert_eq!(
+			DataType::Known(KnownDataType::UInt8)
+				.default_data_type(),
+			DataType::Known(KnownDataType::UInt8)
+		);
+		assert_eq!(
+			DataType::Map(
+				Box::new(DataType::Known(
+					KnownDataType::UntypedInt
+				)),
+				Box::new(DataType::List(Box::new(
+					DataType::Known(KnownDataType::UntypedFloat)
+				))),
+			)
+			.default_data_type(),
+			DataType::Map(
+				Box::new(int64),
+				Box::new(DataType::List(Box::new(double))),
+			)
+		);
+	}
+
+	#[test]
+	fn test_data_type_list()
+	{
+		let target = Target::from("x86_64".to_owned());
+		let int32 = DataType::Known(KnownDataType::Int32);
+		let list_int32 =
+			DataType::List(Box::new(int32.clone()));
+		let list_int64 = DataType::List(Box::new(
+			DataType::Known(KnownDataType::Int64),
+		));
+		let list_untyped = DataType::List(Box::new(
+			DataType::Known(KnownDataType::UntypedInt),
+		));
+
+		assert_eq!(list_int32.to_string(), "List[int32]");
+		assert_eq!(
+			DataType::List(Box::new(list_int32.clone()))
+				.to_string(),
+			"List[List[int32]]"
+		);
+		assert_eq!(
+			list_int32.description(1, "lowercase", None, true),
+			"a list of \u{2068}32-bit integers\u{2069}"
+		);
+
+		assert!(DataType::is_same(
+			&list_int32,
+			&DataType::List(Box::new(int32.clone()))
+		));
+		assert!(!DataType::is_same(&list_int32, &list_int64));
+		assert!(!list_int32.is_numeric());
+		assert!(list_untyped.contains_untyped());
+		assert!(
+			DataType::List(Box::new(list_untyped.clone()))
+				.contains_untyped()
+		);
+		assert!(!list_int32.contains_untyped());
+
+		// lists are invariant in their element data type
+		assert!(!DataType::can_implictly_cast_to(
+			&list_int32,
+			&list_int64,
+			&target
+		));
+		assert!(DataType::can_implictly_cast_to(
+			&list_untyped,
+			&list_int64,
+			&target
+		));
+		assert!(!DataType::can_implictly_cast_to(
+			&list_untyped,
+			&int32,
+			&target
+		));
+		assert_eq!(
+			DataType::binary_expr_result_data_type(
+				&list_untyped,
+				&list_int32,
+				&target
+			),
+			Some(list_int32.clone())
+		);
+	}
+
+	#[test]
+	fn test_data_type_map()
+	{
+		let target = Target::from("x86_64".to_owned());
+		let int64 = DataType::Known(KnownDataType::Int64);
+		let bool = DataType::Known(KnownDataType::Bool);
+		let untyped_int =
+			DataType::Known(KnownDataType::UntypedInt);
+		let map = DataType::Map(
+			Box::new(int64.clone()),
+			Box::new(bool.clone()),
+		);
+		let untyped_map = DataType::Map(
+			Box::new(untyped_int.clone()),
+			Box::new(bool.clone()),
+		);
+
+		assert_eq!(map.to_string(), "Map[int64, bool]");
+		assert_eq!(
+			map.description(1, "lowercase", None, true),
+			"a map from \u{2068}64-bit integers\u{2069} to \
+			 \u{2068}booleans\u{2069}"
+		);
+		assert!(DataType::is_same(&map, &map.clone()));
+		assert!(!DataType::is_same(&map, &untyped_map));
+		assert!(untyped_map.contains_untyped());
+		assert!(!map.contains_untyped());
+
+		// typed keys and values must match, untyped ones are
+		// converted
+		assert!(DataType::can_implictly_cast_to(
+			&untyped_map,
+			&map,
+			&target
+		));
+		assert!(!DataType::can_implictly_cast_to(
+			&map,
+			&untyped_map,
+			&target
+		));
+		assert!(!DataType::can_implictly_cast_to(
+			&DataType::Map(
+				Box::new(DataType::Known(KnownDataType::Int32)),
+				Box::new(bool.clone()),
+			),
+			&map,
+			&target
+		));
+
+		assert!(int64.is_hashable());
+		assert!(bool.is_hashable());
+		assert!(untyped_int.is_hashable());
+		assert!(
+			DataType::Known(KnownDataType::BigInt).is_hashable()
+		);
+		assert!(
+			!DataType::Known(KnownDataType::Double).is_hashable()
+		);
+		assert!(
+			!DataType::Known(KnownDataType::UntypedFloat)
+				.is_hashable()
+		);
+		assert!(!map.is_hashable());
+	}
+
+	#[test]
+	fn test_data_type_big_integer()
+	{
+		let target = Target::from("x86_64".to_owned());
+		let bigint = DataType::Known(KnownDataType::BigInt);
+		let int64 = DataType::Known(KnownDataType::Int64);
+
+		assert!(bigint.is_big_integer());
+		assert!(!bigint.is_generic_integer());
+		assert!(!int64.is_big_integer());
+
+		// fixed width integers need an explicit conversion
+		assert!(!DataType::can_implictly_cast_to(
+			&int64, &bigint, &target
+		));
+		assert!(!DataType::can_implictly_cast_to(
+			&bigint, &int64, &target
+		));
+		assert!(DataType::can_implictly_cast_to(
+			&DataType::Known(KnownDataType::UntypedInt),
+			&bigint,
+			&target
+		));
+		assert_eq!(
+			DataType::binary_expr_result_data_type(
+				&bigint, &int64, &target
+			),
+			None
+		);
 	}
 
 	#[test]
 	fn test_data_type_binary_expr_result_data_type()
 	{
+		let target = Target::from("i686".to_owned());
+
 		assert_eq!(
 			DataType::binary_expr_result_data_type(
 				&DataType::Known(KnownDataType::UInt8),
-				&DataType::Known(KnownDataType::UInt8)
+				&DataType::Known(KnownDataType::UInt8),
+				&target
 			),
 			Some(DataType::Known(KnownDataType::UInt8))
 		);
 		assert_eq!(
 			DataType::binary_expr_result_data_type(
 				&DataType::Known(KnownDataType::UInt8),
-				&DataType::Known(KnownDataType::UInt32)
+				&DataType::Known(KnownDataType::UInt32),
+				&target
 			),
 			Some(DataType::Known(KnownDataType::UInt32))
 		);
 		assert_eq!(
 			DataType::binary_expr_result_data_type(
 				&DataType::Known(KnownDataType::UInt8),
-				&DataType::Known(KnownDataType::Int8)
+				&DataType::Known(KnownDataType::Int8),
+				&target
 			),
 			None
 		);
 		assert_eq!(
 			DataType::binary_expr_result_data_type(
 				&DataType::Known(KnownDataType::UInt8),
-				&DataType::Known(KnownDataType::Int16)
+				&DataType::Known(KnownDataType::Int16),
+				&target
 			),
 			None
 		);
 		assert_eq!(
 			DataType::binary_expr_result_data_type(
 				&DataType::Known(KnownDataType::UInt8),
-				&DataType::Known(KnownDataType::Int64)
+				&DataType::Known(KnownDataType::Int64),
+				&target
 			),
 			None
 		);
 		assert_eq!(
 			DataType::binary_expr_result_data_type(
 				&DataType::Known(KnownDataType::UInt8),
-				&DataType::Known(KnownDataType::Float32)
+				&DataType::Known(KnownDataType::Float32),
+				&target
 			),
 			Some(DataType::Known(KnownDataType::Float32))
 		);
 		assert_eq!(
 			DataType::binary_expr_result_data_type(
 				&DataType::Known(KnownDataType::UInt8),
-				&DataType::Known(KnownDataType::Double)
+				&DataType::Known(KnownDataType::Double),
+				&target
 			),
 			Some(DataType::Known(KnownDataType::Double))
 		);
 		assert_eq!(
 			DataType::binary_expr_result_data_type(
 				&DataType::Known(KnownDataType::Int),
-				&DataType::Known(KnownDataType::Int64)
+				&DataType::Known(KnownDataType::Int64),
+				&target
 			),
 			Some(DataType::Known(KnownDataType::Int64))
 		);
 		assert_eq!(
 			DataType::binary_expr_result_data_type(
 				&DataType::Known(KnownDataType::Int),
-				&DataType::Known(KnownDataType::Double)
+				&DataType::Known(KnownDataType::Double),
+				&target
 			),
 			Some(DataType::Known(KnownDataType::Double))
 		);
 		assert_eq!(
 			DataType::binary_expr_result_data_type(
 				&DataType::Known(KnownDataType::Float32),
-				&DataType::Known(KnownDataType::Double)
+				&DataType::Known(KnownDataType::Double),
+				&target
 			),
 			Some(DataType::Known(KnownDataType::Double))
 		);
 		assert_eq!(
 			DataType::binary_expr_result_data_type(
 				&DataType::Known(KnownDataType::Double),
-				&DataType::Known(KnownDataType::Float32)
+				&DataType::Known(KnownDataType::Float32),
+				&target
 			),
 			Some(DataType::Known(KnownDataType::Double))
 		);
+		assert_eq!(
+			DataType::binary_expr_result_data_type(
+				&DataType::Known(KnownDataType::Bool),
+				&DataType::Known(KnownDataType::Bool),
+				&target
+			),
+			Some(DataType::Known(KnownDataType::Bool))
+		);
+		assert_eq!(
+			DataType::binary_expr_result_data_type(
+				&DataType::Known(KnownDataType::Bool),
+				&DataType::Known(KnownDataType::Int),
+				&target
+			),
+			None
+		);
 		assert_eq!(
 			DataType::binary_expr_result_data_type(
 				&DataType::UserDefined("MyType".into()),
-				&DataType::Known(KnownDataType::Float32)
+				&DataType::Known(KnownDataType::Float32),
+				&target
 			),
 			None
 		);
 	}
+
+	#[test]
+	fn test_data_type_range_and_slice()
+	{
+		let target = Target::from("x86_64".to_owned());
+		let int32 = DataType::Known(KnownDataType::Int32);
+		let untyped_int =
+			DataType::Known(KnownDataType::UntypedInt);
+		let range_int32 =
+			DataType::Range(Box::new(int32.clone()));
+		let range_untyped =
+			DataType::Range(Box::new(untyped_int.clone()));
+		let slice_int32 =
+			DataType::Slice(Box::new(int32.clone()));
+
+		assert_eq!(range_int32.to_string(), "Range[int32]");
+		assert_eq!(slice_int32.to_string(), "Slice[int32]");
+		assert_eq!(
+			range_int32.description(1, "lowercase", None, true),
+			"a range of \u{2068}32-bit integers\u{2069}"
+		);
+		assert_eq!(
+			slice_int32.description(1, "lowercase", None, true),
+			"a slice of \u{2068}32-bit integers\u{2069}"
+		);
+		assert_eq!(range_int32.get_bit_size(&target), 64);
+		assert_eq!(slice_int32.get_bit_size(&target), 128);
+
+		assert!(int32.is_range_bound());
+		assert!(untyped_int.is_range_bound());
+		assert!(
+			DataType::Known(KnownDataType::BigInt)
+				.is_range_bound()
+		);
+		assert!(
+			!DataType::Known(KnownDataType::Double)
+				.is_range_bound()
+		);
+		assert!(
+			!DataType::Known(KnownDataType::Bool)
+				.is_range_bound()
+		);
+
+		// slices are views of lists, not lists
+		assert!(!DataType::is_same(
+			&slice_int32,
+			&DataType::List(Box::new(int32.clone()))
+		));
+		assert!(range_untyped.contains_untyped());
+		assert!(DataType::can_implictly_cast_to(
+			&range_untyped,
+			&range_int32,
+			&target
+		));
+		assert!(!DataType::can_implictly_cast_to(
+			&range_int32,
+			&DataType::Range(Box::new(DataType::Known(
+				KnownDataType::Int64
+			))),
+			&target
+		));
+	}
 }
@@ -0,0 +1,211 @@
+//! # Float Format (float_format) module
+//!
+//! This module defines how floating point values are
+//! printed by `echo`. Every backend must produce exactly
+//! the same text so that programs have the same output
+//! everywhere:
+//!
+//! * `nan` for any NaN, `inf` and `-inf` for infinities.
+//! * Otherwise the shortest decimal digits that parse back
+//!   to the same value in the data type of the value, so
+//!   `1.0f32 / 3` prints `0.33333334` and `1.0 / 3` prints
+//!   `0.3333333333333333`.
+//! * Values with `1e-4 <= |value| < 1e16` and zero use the
+//!   positional notation with at least one fractional
+//!   digit, like `1.0`, `-0.0` or `0.0001`.
+//! * Other values use the scientific notation with a
+//!   mantissa in `[1, 10)`, no `+` sign and no leading
+//!   zeros in the exponent, like `1e16` or `1.5e-7`.
+//!
+//! The output is a valid float literal, so it can be read
+//! back by the lexer without losing precision.
+
+/// Floating point values that can be printed by `echo`
+pub trait FormatFloat
+{
+	/// Format the value using the `echo` formatting rules
+	///
+	/// # Returns
+	///
+	/// The formatted value
+	fn format_float(&self) -> String;
+}
+
+/// `FormatFloat` implementation for `f16`
+impl FormatFloat for f16
+{
+	fn format_float(&self) -> String
+	{
+		format_shortest(&format!("{:e}", self))
+	}
+}
+
+/// `FormatFloat` implementation for `f32`
+impl FormatFloat for f32
+{
+	fn format_float(&self) -> String
+	{
+		format_shortest(&format!("{:e}", self))
+	}
+}
+
+/// `FormatFloat` implementation for `f64`
+impl FormatFloat for f64
+{
+	fn format_float(&self) -> String
+	{
+		format_shortest(&format!("{:e}", self))
+	}
+}
+
+/// Format a float from its shortest round-trip digits,
+/// choosing the notation from the exponent of its first
+/// digit
+///
+/// # Arguments
+///
+/// * `scientific` - The `LowerExp` output of the float,
+///   which has the shortest digits that parse back to the
+///   same value, like `-1.25e-7`, `inf` or `NaN`
+///
+/// # Returns
+///
+/// The formatted value
+fn format_shortest(scientific: &str) -> String
+{
+	let Some((mantissa, exponent)) =
+		scientific.split_once('e')
+	else
+	{
+		// infinities are already formatted
+		return match scientific
+		{
+			"NaN" => "nan".to_owned(),
+			_ => scientific.to_owned(),
+		};
+	};
+	let exponent: i32 = exponent
+		.parse()
+		.expect("the exponent of a float is an integer");
+	let (sign, mantissa) = match mantissa.strip_prefix('-')
+	{
+		Some(mantissa) => ("-", mantissa),
+		None => ("", mantissa),
+	};
+	let digits = mantissa.replace('.', "");
+
+	if digits != "0" && !(-4 .. 16).contains(&exponent)
+	{
+		return format!("{}{}e{}", sign, mantissa, exponent);
+	}
+
+	// the first digit is at the position of the exponent
+	if exponent < 0
+	{
+		let zeros = "0".repeat((-exponent - 1) as usize);
+		return format!("{}0.{}{}", sign, zeros, digits);
+	}
+	let integer_length = exponent as usize + 1;
+	if digits.len() <= integer_length
+	{
+		let zeros = "0".repeat(integer_length - digits.len());
+		format!("{}{}{}.0", sign, digits, zeros)
+	}
+	else
+	{
+		let (integer, fraction) =
+			digits.split_at(integer_length);
+		format!("{}{}.{}", sign, integer, fraction)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::FormatFloat;
+
+	#[test]
+	fn test_format_float()
+	{
+		assert_eq!(1.0_f64.format_float(), "1.0");
+		assert_eq!((-0.0_f64).format_float(), "-0.0");
+		assert_eq!(0.1_f64.format_float(), "0.1");
+		assert_eq!(0.0001_f64.format_float(), "0.0001");
+		assert_eq!(
+			(1.0_f64 / 3.0).format_float(),
+			"0.3333333333333333"
+		);
+		assert_eq!(
+			(1.0_f32 / 3.0).format_float(),
+			"0.33333334"
+		);
+		assert_eq!((1.0_f16 / 3.0).format_float(), "0.3333");
+		assert_eq!(1e16_f64.format_float(), "1e16");
+		assert_eq!(1.5e-7_f64.format_float(), "1.5e-7");
+		assert_eq!(f32::MAX.format_float(), "3.4028235e38");
+	}
+
+	#[test]
+	fn test_format_float_special_values()
+	{
+		assert_eq!(f64::NAN.format_float(), "nan");
+		assert_eq!((-f64::NAN).format_float(), "nan");
+		assert_eq!(f32::INFINITY.format_float(), "inf");
+		assert_eq!(f64::NEG_INFINITY.format_float(), "-inf");
+		assert_eq!(f16::NAN.format_float(), "nan");
+	}
+
+	#[test]
+	fn test_format_float_round_trip()
+	{
+		for value in
+			[0.1_f64, 1.0 / 3.0, 1e300, 5e-324, 123.456]
+		{
+			assert_eq!(
+				value.format_float().parse::<f64>().unwrap(),
+				value
+			);
+		}
+		for value in [0.1_f32, 1.0 / 3.0, f32::MIN_POSITIVE]
+		{
+			assert_eq!(
+				value.format_float().parse::<f32>().unwrap(),
+				value
+			);
+		}
+	}
+
+	#[test]
+	fn test_format_float_notation_boundaries()
+	{
+		assert_eq!(1e-5_f64.format_float(), "1e-5");
+		assert_eq!(1e-4_f64.format_float(), "0.0001");
+		assert_eq!(
+			1e15_f64.format_float(),
+			"1000000000000000.0"
+		);
+		assert_eq!(1e16_f64.format_float(), "1e16");
+		assert_eq!(
+			9999999999999998.0_f64.format_float(),
+			"9999999999999998.0"
+		);
+		assert_eq!((-1.25e-4_f64).format_float(), "-0.000125");
+		assert_eq!(1.5e16_f64.format_float(), "1.5e16");
+
+		assert_eq!(1e-5_f32.format_float(), "1e-5");
+		assert_eq!(1e-4_f32.format_float(), "0.0001");
+		assert_eq!(
+			1e15_f32.format_float(),
+			"1000000000000000.0"
+		);
+		assert_eq!(1e16_f32.format_float(), "1e16");
+		assert_eq!((-0.0_f32).format_float(), "-0.0");
+
+		assert_eq!(1e-5_f16.format_float(), "1e-5");
+		assert_eq!(1e-4_f16.format_float(), "0.0001");
+		assert_eq!(65504.0_f16.format_float(), "65500.0");
+		// float16 can't hold values this large
+		assert_eq!((1e15_f64 as f16).format_float(), "inf");
+		assert_eq!((1e16_f64 as f16).format_float(), "inf");
+	}
+}
@@ -11,7 +11,9 @@ use crate::parser::lexer::Lexer;
 use crate::parser::Parser;
 
 pub mod annotated_ast;
+pub mod constant;
 pub mod data_type;
+pub mod float_format;
 pub mod semantic_checker;
 pub mod session_globals;
 
@@ -72,7 +74,7 @@ where
 fn parse_source<'i, I, O, E>(
 	source_id: &SmolStr,
 	source_code: &String,
-	_session_globals: &mut SessionGlobals<'i, I, O, E>,
+	session_globals: &mut SessionGlobals<'i, I, O, E>,
 ) -> Result<(), Error>
 where
 	I: BufRead,
@@ -97,7 +99,12 @@ where
 				)
 			})?;
 
-	let annotated_module = SemanticChecker::check(source_id.clone(), module)
+	let (annotated_module, warnings) =
+		SemanticChecker::check(
+			source_id.clone(),
+			module,
+			&session_globals.config,
+		)
 		.map_err(|error| {
 			Error::new(
 				ErrorKind::SemanticCheckerError(error),
@@ -105,6 +112,11 @@ where
 			)
 		})?;
 
+	for warning in warnings
+	{
+		warning.print(session_globals);
+	}
+
 	println!("{:#?}", annotated_module);
 	Ok(())
 }
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::io::BufRead;
 use std::ops::Range;
 use std::vec;
@@ -8,33 +9,64 @@ use codespan_reporting::diagnostic::{
 	LabelStyle,
 };
 use codespan_reporting::files::SimpleFiles;
+use num_bigint::BigInt;
+use num_traits::Signed;
 use smol_str::SmolStr;
 use termcolor::WriteColor;
 
 use super::annotated_ast::{
+	AnnotatedAssertStmt,
+	AnnotatedAttributedStmt,
 	AnnotatedBinaryExpr,
+	AnnotatedBlockExpr,
+	AnnotatedDeferStmt,
 	AnnotatedEchoStmt,
 	AnnotatedExpression,
 	AnnotatedExpressionStmt,
 	AnnotatedFunctionDeclStmt,
+	AnnotatedIfExpr,
+	AnnotatedListExpr,
 	AnnotatedLiteralExpr,
+	AnnotatedMapExpr,
 	AnnotatedModule,
+	AnnotatedRangeExpr,
+	AnnotatedSliceExpr,
 	AnnotatedStatement,
+	AnnotatedStaticAssertStmt,
 	AnnotatedUnaryExpr,
 };
+use super::constant::{
+	Constant,
+	ConstantErrorCode,
+	MAX_INT_BITS,
+};
 use super::data_type::{DataType, KnownDataType};
 use super::session_globals::SessionGlobals;
+use crate::common::config::{Config, OverflowPolicy};
+use crate::common::target::Target;
+use crate::common::utils::find_similar_name;
 use crate::parser::ast::{
+	AssertStmt,
 	AstVisitor,
+	Attribute,
+	AttributedStmt,
 	BinaryExpr,
+	BlockExpr,
+	DeferStmt,
 	EchoStmt,
 	ExpressionStmt,
 	GetSpan,
+	IfExpr,
+	ListExpr,
+	MapEntry,
+	MapExpr,
+	RangeExpr,
+	SliceExpr,
 	UnaryExpr,
 	{self},
 };
-use crate::parser::span::Location;
-use crate::t;
+use crate::parser::span::{Location, Span};
+use crate::{t, ternary};
 
 /// Semantic Checker error code
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -42,6 +74,66 @@ pub enum SemanticCheckerErrorCode
 {
 	/// Invalid operand
 	InvalidOperand,
+	/// Condition is not a boolean
+	InvalidCondition,
+	/// Branches of an if expression have incompatible types
+	IncompatibleBranches,
+	/// Elements of a list have incompatible types
+	HeterogeneousList,
+	/// Element type of an empty list cannot be inferred
+	EmptyList,
+	/// Keys or values of a map have incompatible types
+	HeterogeneousMap,
+	/// Map keys cannot be hashed
+	UnhashableKey,
+	/// Constant key appears more than once in a map
+	DuplicateMapKey,
+	/// Bounds of a range are not compatible integers
+	InvalidRange,
+	/// Expression cannot be sliced or is not sliced by a
+	/// range
+	InvalidSlice,
+	/// Constant slice range is outside of the list
+	SliceOutOfBounds,
+	/// Condition of a static assertion cannot be evaluated
+	/// at compile time
+	NonConstantStaticAssert,
+	/// Condition of a static assertion is false
+	StaticAssertFailed,
+	/// Attribute is not known to the compiler
+	UnknownAttribute,
+	/// Attribute has too many arguments
+	InvalidAttributeArguments,
+	/// Attribute cannot be applied to the statement
+	InvalidAttributeTarget,
+	/// Defer statement is deferred itself
+	NestedDefer,
+	/// Untyped constant does not fit in its context data type
+	ConstantOverflow,
+	/// Constant expression divides by zero or raises an
+	/// integer to a negative power
+	InvalidConstantOperation,
+	/// Code relies on `int` or `uint` being a particular
+	/// width
+	NonPortableIntWidth,
+}
+
+/// Implementation of `SemanticCheckerErrorCode`
+impl SemanticCheckerErrorCode
+{
+	/// Check if the code is reported as a warning
+	///
+	/// # Returns
+	///
+	/// `true` if the code is a lint warning, otherwise
+	/// `false`
+	pub fn is_warning(&self) -> bool
+	{
+		matches!(
+			self,
+			SemanticCheckerErrorCode::NonPortableIntWidth
+		)
+	}
 }
 
 /// Semantic Checker error
@@ -96,17 +188,21 @@ impl SemanticCheckerError
 			})
 			.collect::<Vec<Label<_>>>();
 
-		let diagnostic = Diagnostic::error()
-			.with_code(format!("{:?}", self.code))
-			.with_message(self.message.clone().to_string() + "\n")
-			.with_labels(labels)
-			.with_notes(
-				self
-					.hint
-					.clone()
-					.map(|hint| vec![hint])
-					.unwrap_or_default(),
-			);
+		let diagnostic = ternary!(
+			self.code.is_warning(),
+			Diagnostic::warning(),
+			Diagnostic::error()
+		)
+		.with_code(format!("{:?}", self.code))
+		.with_message(self.message.clone().to_string() + "\n")
+		.with_labels(labels)
+		.with_notes(
+			self
+				.hint
+				.clone()
+				.map(|hint| vec![hint])
+				.unwrap_or_default(),
+		);
 
 		(diagnostic_files, file_id, diagnostic)
 	}
@@ -147,6 +243,14 @@ pub struct SemanticChecker
 	source_id: SmolStr,
 	/// The module to check
 	module: ast::Module<ast::Expression>,
+	/// The overflow policy of arithmetic operations
+	overflow_policy: OverflowPolicy,
+	/// Whether assert statements are compiled
+	debug_assertions: bool,
+	/// The target machine
+	target: Target,
+	/// The warnings found while checking
+	warnings: Vec<SemanticCheckerError>,
 }
 
 impl
@@ -203,6 +307,22 @@ impl
 			{
 				self.visit_echo_stmt(stmt)
 			}
+			ast::Statement::Assert(stmt) =>
+			{
+				self.visit_assert_stmt(stmt)
+			}
+			ast::Statement::StaticAssert(stmt) =>
+			{
+				self.visit_static_assert_stmt(stmt)
+			}
+			ast::Statement::Attributed(stmt) =>
+			{
+				self.visit_attributed_stmt(stmt)
+			}
+			ast::Statement::Defer(stmt) =>
+			{
+				self.visit_defer_stmt(stmt)
+			}
 			ast::Statement::FunctionDeclaration(func) =>
 			{
 				self.visit_function_decl_stmt(func)
@@ -260,12 +380,15 @@ impl
 	{
 		let annotated_expression =
 			self.visit_expression(&expression_stmt.expression)?;
+		let annotated_expression =
+			self.default_untyped_expr(annotated_expression)?;
+		let data_type = annotated_expression.get_data_type().clone();
 		Ok(AnnotatedStatement::Expression(
 			AnnotatedExpressionStmt {
 				inner: ExpressionStmt {
 					expression: annotated_expression,
 				},
-				data_type: None,
+				data_type: Some(data_type),
 			},
 		))
 	}
@@ -281,6 +404,8 @@ impl
 	{
 		let annotated_expression =
 			self.visit_expression(&echo_stmt.expression)?;
+		let annotated_expression =
+			self.default_untyped_expr(annotated_expression)?;
 		Ok(AnnotatedStatement::Echo(AnnotatedEchoStmt {
 			inner: EchoStmt {
 				echo_token: echo_stmt.echo_token.clone(),
@@ -290,6 +415,151 @@ impl
 		}))
 	}
 
+	/// Visit and check the assert statement node
+	/// for semantic errors
+	fn visit_assert_stmt(
+		&mut self,
+		assert_stmt: &ast::AssertStmt<ast::Expression>,
+	) -> SemanticCheckerResult<
+		AnnotatedStatement<AnnotatedExpression>,
+	>
+	{
+		let condition =
+			self.visit_expression(&assert_stmt.condition)?;
+		self.check_condition(
+			assert_stmt
+				.assert_token
+				.as_ref()
+				.map(|token| token.span),
+			&assert_stmt.condition,
+			condition.get_data_type(),
+			"assert",
+		)?;
+
+		Ok(AnnotatedStatement::Assert(AnnotatedAssertStmt {
+			inner: AssertStmt {
+				assert_token: assert_stmt.assert_token.clone(),
+				condition,
+			},
+			data_type: None,
+			is_enabled: self.debug_assertions,
+		}))
+	}
+
+	/// Visit and check the static assert statement node
+	/// for semantic errors
+	fn visit_static_assert_stmt(
+		&mut self,
+		static_assert_stmt: &ast::AssertStmt<ast::Expression>,
+	) -> SemanticCheckerResult<
+		AnnotatedStatement<AnnotatedExpression>,
+	>
+	{
+		let condition = self
+			.visit_expression(&static_assert_stmt.condition)?;
+		self.check_condition(
+			static_assert_stmt
+				.assert_token
+				.as_ref()
+				.map(|token| token.span),
+			&static_assert_stmt.condition,
+			condition.get_data_type(),
+			"static-assert",
+		)?;
+		self.check_static_assertion(
+			static_assert_stmt,
+			&condition,
+		)?;
+
+		Ok(AnnotatedStatement::StaticAssert(
+			AnnotatedStaticAssertStmt {
+				inner: AssertStmt {
+					assert_token: static_assert_stmt
+						.assert_token
+						.clone(),
+					condition,
+				},
+				data_type: None,
+			},
+		))
+	}
+
+	/// Visit and check the attributed statement node
+	/// for semantic errors
+	fn visit_attributed_stmt(
+		&mut self,
+		attributed_stmt: &ast::AttributedStmt<
+			ast::Expression,
+			ast::Statement<ast::Expression>,
+		>,
+	) -> SemanticCheckerResult<
+		AnnotatedStatement<AnnotatedExpression>,
+	>
+	{
+		let mut attributes = vec![];
+		for attribute in &attributed_stmt.attributes
+		{
+			self.check_attribute(
+				attribute,
+				&attributed_stmt.statement,
+			)?;
+
+			let mut arguments = vec![];
+			for argument in &attribute.arguments
+			{
+				let argument = self.visit_expression(argument)?;
+				arguments
+					.push(self.default_untyped_expr(argument)?);
+			}
+			attributes.push(Attribute {
+				attribute_token: attribute.attribute_token.clone(),
+				left_paren_token: attribute
+					.left_paren_token
+					.clone(),
+				arguments,
+				right_paren_token: attribute
+					.right_paren_token
+					.clone(),
+			});
+		}
+		let statement =
+			self.visit_statement(&attributed_stmt.statement)?;
+
+		Ok(AnnotatedStatement::Attributed(
+			AnnotatedAttributedStmt {
+				inner: AttributedStmt {
+					attributes,
+					statement: Box::new(statement),
+				},
+				data_type: None,
+			},
+		))
+	}
+
+	/// Visit and check the defer statement node
+	/// for semantic errors
+	fn visit_defer_stmt(
+		&mut self,
+		defer_stmt: &ast::DeferStmt<
+			ast::Statement<ast::Expression>,
+		>,
+	) -> SemanticCheckerResult<
+		AnnotatedStatement<AnnotatedExpression>,
+	>
+	{
+		self.check_deferred_statement(defer_stmt)?;
+
+		let statement =
+			self.visit_statement(&defer_stmt.statement)?;
+		Ok(AnnotatedStatement::Defer(AnnotatedDeferStmt {
+			inner: DeferStmt {
+				defer_token: defer_stmt.defer_token.clone(),
+				statement: Box::new(statement),
+			},
+			data_type: None,
+		}))
+	}
+
 	/// Visit and check the expression node
 	/// for semantic errors
 	fn visit_expression(
@@ -315,6 +585,30 @@ impl
 			{
 				self.visit_binary_expr(&binary)
 			}
+			ast::Expression::Block(block) =>
+			{
+				self.visit_block_expr(&block)
+			}
+			ast::Expression::If(if_expr) =>
+			{
+				self.visit_if_expr(&if_expr)
+			}
+			ast::Expression::List(list) =>
+			{
+				self.visit_list_expr(&list)
+			}
+			ast::Expression::Map(map) =>
+			{
+				self.visit_map_expr(&map)
+			}
+			ast::Expression::Range(range) =>
+			{
+				self.visit_range_expr(&range)
+			}
+			ast::Expression::Slice(slice) =>
+			{
+				self.visit_slice_expr(&slice)
+			}
 		}
 	}
 
@@ -353,6 +647,8 @@ impl
 							.clone(),
 						right: Box::new(right_data_type),
 					},
+					overflow_policy: self
+						.overflow_policy_for(&data_type),
 					data_type,
 				}))
 			}
@@ -422,6 +718,17 @@ impl
 					},
 				))
 			}
+			ast::Value::UInt128(_) =>
+			{
+				Ok(AnnotatedExpression::Literal(
+					AnnotatedLiteralExpr {
+						inner: literal.clone(),
+						data_type: DataType::Known(
+							KnownDataType::UInt128,
+						),
+					},
+				))
+			}
 			ast::Value::Int8(_) =>
 			{
 				Ok(AnnotatedExpression::Literal(
@@ -464,6 +771,28 @@ impl
 					},
 				))
 			}
+			ast::Value::Int128(_) =>
+			{
+				Ok(AnnotatedExpression::Literal(
+					AnnotatedLiteralExpr {
+						inner: literal.clone(),
+						data_type: DataType::Known(
+							KnownDataType::Int128,
+						),
+					},
+				))
+			}
+			ast::Value::BigInt(_) =>
+			{
+				Ok(AnnotatedExpression::Literal(
+					AnnotatedLiteralExpr {
+						inner: literal.clone(),
+						data_type: DataType::Known(
+							KnownDataType::BigInt,
+						),
+					},
+				))
+			}
 			ast::Value::Int(_) =>
 			{
 				Ok(AnnotatedExpression::Literal(
@@ -482,6 +811,17 @@ impl
 					},
 				))
 			}
+			ast::Value::Float16(_) =>
+			{
+				Ok(AnnotatedExpression::Literal(
+					AnnotatedLiteralExpr {
+						inner: literal.clone(),
+						data_type: DataType::Known(
+							KnownDataType::Float16,
+						),
+					},
+				))
+			}
 			ast::Value::Float32(_) =>
 			{
 				Ok(AnnotatedExpression::Literal(
@@ -504,6 +844,37 @@ impl
 					},
 				))
 			}
+			ast::Value::Bool(_) =>
+			{
+				Ok(AnnotatedExpression::Literal(
+					AnnotatedLiteralExpr {
+						inner: literal.clone(),
+						data_type: DataType::Known(KnownDataType::Bool),
+					},
+				))
+			}
+			ast::Value::UntypedInt(_) =>
+			{
+				Ok(AnnotatedExpression::Literal(
+					AnnotatedLiteralExpr {
+						inner: literal.clone(),
+						data_type: DataType::Known(
+							KnownDataType::UntypedInt,
+						),
+					},
+				))
+			}
+			ast::Value::UntypedFloat(_) =>
+			{
+				Ok(AnnotatedExpression::Literal(
+					AnnotatedLiteralExpr {
+						inner: literal.clone(),
+						data_type: DataType::Known(
+							KnownDataType::UntypedFloat,
+						),
+					},
+				))
+			}
 		}
 	}
 
@@ -543,6 +914,31 @@ impl
 						&right_data_type.get_data_type(),
 					)?;
 
+				// untyped operands take the data type of the
+				// other operand
+				let left_span = left_data_type.get_span();
+				let right_span = right_data_type.get_span();
+				self.check_portable_conversion(
+					&left_data_type,
+					&data_type,
+					right_span,
+				);
+				self.check_portable_conversion(
+					&right_data_type,
+					&data_type,
+					left_span,
+				);
+				let left_data_type = self.convert_untyped_expr(
+					left_data_type,
+					&data_type,
+					right_span,
+				)?;
+				let right_data_type = self.convert_untyped_expr(
+					right_data_type,
+					&data_type,
+					left_span,
+				)?;
+
 				Ok(AnnotatedExpression::Binary(
 					AnnotatedBinaryExpr {
 						inner: BinaryExpr {
@@ -551,97 +947,464 @@ impl
 							operator_token: binary.operator_token.clone(),
 							right: Box::new(right_data_type),
 						},
+						overflow_policy: self
+							.overflow_policy_for(&data_type),
 						data_type,
 					},
 				))
 			}
 		}
 	}
-}
 
-impl SemanticChecker
-{
-	/// Create a new semantic checker
+	/// Visit and check the block expression
+	/// for semantic errors
 	///
 	/// # Arguments
 	///
-	/// * `source_id` - The source ID
-	/// * `module` - The module to check
+	/// * `block_expr` - The block expression
 	///
 	/// # Returns
 	///
-	/// The semantic checker
-	fn new(
-		source_id: SmolStr,
-		module: ast::Module<ast::Expression>,
-	) -> Self
+	/// The annotated block expression whose data type is the
+	/// data type of its trailing expression
+	fn visit_block_expr(
+		&mut self,
+		block_expr: &ast::BlockExpr<
+			ast::Expression,
+			ast::Statement<ast::Expression>,
+		>,
+	) -> SemanticCheckerResult<AnnotatedExpression>
 	{
-		Self { source_id, module }
+		let mut statements: Vec<
+			Box<AnnotatedStatement<AnnotatedExpression>>,
+		> = vec![];
+		for statement in &block_expr.statements
+		{
+			statements.push(Box::new(self.visit_statement(statement)?));
+		}
+
+		let expression =
+			self.visit_expression(&block_expr.expression)?;
+		let data_type = expression.get_data_type().clone();
+
+		Ok(AnnotatedExpression::Block(AnnotatedBlockExpr {
+			inner: BlockExpr {
+				left_brace_token: block_expr.left_brace_token.clone(),
+				statements,
+				expression: Box::new(expression),
+				right_brace_token: block_expr
+					.right_brace_token
+					.clone(),
+			},
+			data_type,
+		}))
 	}
 
-	/// Check the module for semantic errors
-	/// and return the result
+	/// Visit and check the if expression
+	/// for semantic errors
 	///
 	/// # Arguments
 	///
-	/// * `source_id` - The source ID
-	/// * `module` - The module to check
+	/// * `if_expr` - The if expression
 	///
 	/// # Returns
 	///
-	/// `void`
-	///
-	/// # Errors
-	///
-	/// If there is a semantic error, it will return an error
-	pub fn check(
-		source_id: SmolStr,
-		module: ast::Module<ast::Expression>,
-	) -> SemanticCheckerResult<
-		AnnotatedModule<AnnotatedExpression>,
-	>
+	/// The annotated if expression whose data type is the
+	/// unified data type of both branches
+	fn visit_if_expr(
+		&mut self,
+		if_expr: &ast::IfExpr<ast::Expression>,
+	) -> SemanticCheckerResult<AnnotatedExpression>
 	{
-		let mut checker = Self::new(source_id, module);
-		checker.visit_module(&checker.module.clone())
+		let condition = self.visit_expression(&if_expr.condition)?;
+		self.check_condition(
+			if_expr.if_token.as_ref().map(|token| token.span),
+			&if_expr.condition,
+			condition.get_data_type(),
+			"if",
+		)?;
+
+		let then_branch =
+			self.visit_expression(&if_expr.then_branch)?;
+		let else_branch =
+			self.visit_expression(&if_expr.else_branch)?;
+
+		let data_type = self.check_if_branches(
+			if_expr,
+			then_branch.get_data_type(),
+			else_branch.get_data_type(),
+		)?;
+
+		// untyped branches take the data type of the other
+		// branch
+		let then_span = then_branch.get_span();
+		let else_span = else_branch.get_span();
+		self.check_portable_conversion(
+			&then_branch,
+			&data_type,
+			else_span,
+		);
+		self.check_portable_conversion(
+			&else_branch,
+			&data_type,
+			then_span,
+		);
+		let then_branch = self.convert_untyped_expr(
+			then_branch,
+			&data_type,
+			else_span,
+		)?;
+		let else_branch = self.convert_untyped_expr(
+			else_branch,
+			&data_type,
+			then_span,
+		)?;
+
+		Ok(AnnotatedExpression::If(AnnotatedIfExpr {
+			inner: IfExpr {
+				if_token: if_expr.if_token.clone(),
+				condition: Box::new(condition),
+				then_token: if_expr.then_token.clone(),
+				then_branch: Box::new(then_branch),
+				else_token: if_expr.else_token.clone(),
+				else_branch: Box::new(else_branch),
+			},
+			data_type,
+		}))
 	}
 
-	/// Check the operands for unary negate
+	/// Visit and check the list expression
+	/// for semantic errors
 	///
 	/// # Arguments
 	///
-	/// * `unary` - The unary expression
-	/// * `rhs` - The right-hand side data type
+	/// * `list_expr` - The list expression
 	///
 	/// # Returns
 	///
-	/// The data type of the unary expression
+	/// The annotated list expression whose data type is a
+	/// list of the unified data type of its elements
+	fn visit_list_expr(
+		&mut self,
+		list_expr: &ast::ListExpr<ast::Expression>,
+	) -> SemanticCheckerResult<AnnotatedExpression>
+	{
+		let mut elements = vec![];
+		for element in &list_expr.elements
+		{
+			elements.push(self.visit_expression(element)?);
+		}
+
+		let (element_data_type, deciding_index) =
+			self.check_list_elements(list_expr, &elements)?;
+		let elements = self.convert_elements(
+			elements,
+			&element_data_type,
+			deciding_index,
+		)?;
+
+		Ok(AnnotatedExpression::List(AnnotatedListExpr {
+			inner: ListExpr {
+				left_bracket_token: list_expr
+					.left_bracket_token
+					.clone(),
+				elements,
+				right_bracket_token: list_expr
+					.right_bracket_token
+					.clone(),
+			},
+			data_type: DataType::List(Box::new(
+				element_data_type,
+			)),
+		}))
+	}
+
+	/// Visit and check the map expression
+	/// for semantic errors
 	///
-	/// # Errors
+	/// # Arguments
 	///
-	/// If the operands are invalid, it will return an error
-	fn check_unary_negate_operands(
-		&self,
-		unary: &ast::UnaryExpr<ast::Expression>,
-		rhs: &DataType,
-	) -> SemanticCheckerResult<DataType>
+	/// * `map_expr` - The map expression
+	///
+	/// # Returns
+	///
+	/// The annotated map expression whose data type is a map
+	/// from the unified data type of its keys to the unified
+	/// data type of its values
+	fn visit_map_expr(
+		&mut self,
+		map_expr: &ast::MapExpr<ast::Expression>,
+	) -> SemanticCheckerResult<AnnotatedExpression>
 	{
-		// only signed integer and floating point are allowed
-		if !rhs.is_floating_point() || !rhs.is_signed_integer()
+		let mut keys = vec![];
+		let mut values = vec![];
+		for entry in &map_expr.entries
 		{
-			let mut labels: Vec<(LabelStyle, Location, String)> =
-				vec![];
+			keys.push(self.visit_expression(&entry.key)?);
+			values.push(self.visit_expression(&entry.value)?);
+		}
 
-			let operator_description = unary
-				.operator_token
-				.as_ref()
-				.map(|token| {
-					token.description(
-						1,
-						"lowercase",
-						None,
-						false,
-						true,
-					)
+		// the parser only creates maps with at least one entry
+		let (key_data_type, key_index) = self
+			.unify_element_data_types(
+				&keys,
+				SemanticCheckerErrorCode::HeterogeneousMap,
+				t!(
+					"semantic-checker-error-heterogeneous-elements.\
+					 map-key-message"
+				),
+			)?;
+		self.check_map_key(&keys[key_index], &key_data_type)?;
+		let (value_data_type, value_index) = self
+			.unify_element_data_types(
+				&values,
+				SemanticCheckerErrorCode::HeterogeneousMap,
+				t!(
+					"semantic-checker-error-heterogeneous-elements.\
+					 map-value-message"
+				),
+			)?;
+
+		let keys = self.convert_elements(
+			keys,
+			&key_data_type,
+			key_index,
+		)?;
+		let values = self.convert_elements(
+			values,
+			&value_data_type,
+			value_index,
+		)?;
+		self.check_duplicate_keys(&keys)?;
+
+		Ok(AnnotatedExpression::Map(AnnotatedMapExpr {
+			inner: MapExpr {
+				left_brace_token: map_expr.left_brace_token.clone(),
+				entries: keys
+					.into_iter()
+					.zip(values)
+					.zip(&map_expr.entries)
+					.map(|((key, value), entry)| MapEntry {
+						key,
+						colon_token: entry.colon_token.clone(),
+						value,
+					})
+					.collect(),
+				right_brace_token: map_expr
+					.right_brace_token
+					.clone(),
+			},
+			data_type: DataType::Map(
+				Box::new(key_data_type),
+				Box::new(value_data_type),
+			),
+		}))
+	}
+
+	/// Visit and check the range expression
+	/// for semantic errors
+	///
+	/// # Arguments
+	///
+	/// * `range_expr` - The range expression
+	///
+	/// # Returns
+	///
+	/// The annotated range expression whose data type is a
+	/// range of the unified data type of its bounds
+	fn visit_range_expr(
+		&mut self,
+		range_expr: &ast::RangeExpr<ast::Expression>,
+	) -> SemanticCheckerResult<AnnotatedExpression>
+	{
+		let start = self.visit_expression(&range_expr.start)?;
+		let end = self.visit_expression(&range_expr.end)?;
+
+		let data_type = self.check_range_bounds(
+			range_expr,
+			start.get_data_type(),
+			end.get_data_type(),
+		)?;
+
+		// an untyped bound takes the data type of the other
+		// bound
+		let start_span = start.get_span();
+		let end_span = end.get_span();
+		self.check_portable_conversion(
+			&start, &data_type, end_span,
+		);
+		self.check_portable_conversion(
+			&end, &data_type, start_span,
+		);
+		let start = self
+			.convert_untyped_expr(start, &data_type, end_span)?;
+		let end = self
+			.convert_untyped_expr(end, &data_type, start_span)?;
+
+		Ok(AnnotatedExpression::Range(AnnotatedRangeExpr {
+			inner: RangeExpr {
+				start: Box::new(start),
+				operator_token: range_expr.operator_token.clone(),
+				end: Box::new(end),
+			},
+			data_type: DataType::Range(Box::new(data_type)),
+		}))
+	}
+
+	/// Visit and check the slice expression
+	/// for semantic errors
+	///
+	/// # Arguments
+	///
+	/// * `slice_expr` - The slice expression
+	///
+	/// # Returns
+	///
+	/// The annotated slice expression whose data type is a
+	/// slice of the element data type of the sliced list
+	fn visit_slice_expr(
+		&mut self,
+		slice_expr: &ast::SliceExpr<ast::Expression>,
+	) -> SemanticCheckerResult<AnnotatedExpression>
+	{
+		let target =
+			self.visit_expression(&slice_expr.target)?;
+		let range = self.visit_expression(&slice_expr.range)?;
+
+		let element_data_type =
+			self.check_slice_operands(&target, &range)?;
+		self.check_slice_bounds(&target, &range)?;
+
+		// ranges of untyped constants index with `uint`
+		let range = self.convert_untyped_expr(
+			range,
+			&DataType::Range(Box::new(DataType::Known(
+				KnownDataType::UInt,
+			))),
+			None,
+		)?;
+
+		Ok(AnnotatedExpression::Slice(AnnotatedSliceExpr {
+			inner: SliceExpr {
+				target: Box::new(target),
+				left_bracket_token: slice_expr
+					.left_bracket_token
+					.clone(),
+				range: Box::new(range),
+				right_bracket_token: slice_expr
+					.right_bracket_token
+					.clone(),
+			},
+			data_type: DataType::Slice(Box::new(
+				element_data_type,
+			)),
+		}))
+	}
+}
+
+impl SemanticChecker
+{
+	/// Create a new semantic checker
+	///
+	/// # Arguments
+	///
+	/// * `source_id` - The source ID
+	/// * `module` - The module to check
+	/// * `config` - The configuration
+	///
+	/// # Returns
+	///
+	/// The semantic checker
+	fn new(
+		source_id: SmolStr,
+		module: ast::Module<ast::Expression>,
+		config: &Config,
+	) -> Self
+	{
+		Self {
+			source_id,
+			module,
+			overflow_policy: config.overflow_policy(),
+			debug_assertions: config.debug_assertions(),
+			target: config.target(),
+			warnings: vec![],
+		}
+	}
+
+	/// Check the module for semantic errors
+	/// and return the result
+	///
+	/// # Arguments
+	///
+	/// * `source_id` - The source ID
+	/// * `module` - The module to check
+	/// * `config` - The configuration providing the overflow
+	///   policy and the target machine
+	///
+	/// # Returns
+	///
+	/// The annotated module and the warnings found while
+	/// checking it
+	///
+	/// # Errors
+	///
+	/// If there is a semantic error, it will return an error
+	pub fn check(
+		source_id: SmolStr,
+		module: ast::Module<ast::Expression>,
+		config: &Config,
+	) -> SemanticCheckerResult<(
+		AnnotatedModule<AnnotatedExpression>,
+		Vec<SemanticCheckerError>,
+	)>
+	{
+		let mut checker = Self::new(source_id, module, config);
+		let module =
+			checker.visit_module(&checker.module.clone())?;
+		Ok((module, checker.warnings))
+	}
+
+	/// Check the operands for unary negate
+	///
+	/// # Arguments
+	///
+	/// * `unary` - The unary expression
+	/// * `rhs` - The right-hand side data type
+	///
+	/// # Returns
+	///
+	/// The data type of the unary expression
+	///
+	/// # Errors
+	///
+	/// If the operands are invalid, it will return an error
+	fn check_unary_negate_operands(
+		&self,
+		unary: &ast::UnaryExpr<ast::Expression>,
+		rhs: &DataType,
+	) -> SemanticCheckerResult<DataType>
+	{
+		// only signed integer, bigint, floating point and
+		// untyped constants are allowed
+		if !rhs.is_floating_point()
+			&& !rhs.is_signed_integer()
+			&& !rhs.is_big_integer()
+			&& !rhs.is_untyped()
+		{
+			let mut labels: Vec<(LabelStyle, Location, String)> =
+				vec![];
+
+			let operator_description = unary
+				.operator_token
+				.as_ref()
+				.map(|token| {
+					token.description(
+						1,
+						"lowercase",
+						None,
+						false,
+						true,
+					)
 				})
 				.unwrap_or_else(|| {
 					t!(
@@ -712,8 +1475,16 @@ impl SemanticChecker
 		rhs: &DataType,
 	) -> SemanticCheckerResult<DataType>
 	{
-		let maybe_result_data_type =
-			DataType::binary_expr_result_data_type(&lhs, &rhs);
+		// only numeric operands are allowed
+		let maybe_result_data_type = ternary!(
+			lhs.is_numeric() && rhs.is_numeric(),
+			DataType::binary_expr_result_data_type(
+				&lhs,
+				&rhs,
+				&self.target
+			),
+			None
+		);
 
 		if maybe_result_data_type.is_none()
 		{
@@ -787,4 +1558,2470 @@ impl SemanticChecker
 
 		Ok(maybe_result_data_type.unwrap())
 	}
+
+	/// Check that the condition of a static assertion is
+	/// true at compile time
+	///
+	/// # Arguments
+	///
+	/// * `static_assert_stmt` - The static assert statement
+	/// * `condition` - The annotated condition
+	///
+	/// # Errors
+	///
+	/// If the condition cannot be evaluated or is false, it
+	/// will return an error
+	fn check_static_assertion(
+		&self,
+		static_assert_stmt: &ast::AssertStmt<ast::Expression>,
+		condition: &AnnotatedExpression,
+	) -> SemanticCheckerResult<()>
+	{
+		let value = Constant::evaluate_bool(condition);
+		if value == Some(true)
+		{
+			return Ok(());
+		}
+
+		let (code, key) = ternary!(
+			value.is_none(),
+			(
+				SemanticCheckerErrorCode::NonConstantStaticAssert,
+				"semantic-checker-error-non-constant-static-assert"
+			),
+			(
+				SemanticCheckerErrorCode::StaticAssertFailed,
+				"semantic-checker-error-static-assert-failed"
+			)
+		);
+
+		let mut labels: Vec<(LabelStyle, Location, String)> =
+			vec![];
+
+		if let Some(condition_span) = condition.get_span()
+		{
+			labels.push((
+				LabelStyle::Primary,
+				Location::Span(condition_span),
+				t!(format!("{key}.label")),
+			));
+		}
+		if let Some(assert_token) =
+			&static_assert_stmt.assert_token
+		{
+			labels.push((
+				LabelStyle::Secondary,
+				Location::Span(assert_token.span),
+				t!(format!("{key}.assert-label")),
+			));
+		}
+
+		Err(SemanticCheckerError {
+			code,
+			message: t!(format!("{key}.message")),
+			hint: Some(t!(format!("{key}.hint"))),
+			labels,
+			source_id: self.source_id.clone(),
+		})
+	}
+
+	/// Check that the deferred statement is not a defer
+	/// statement itself, also behind attributes
+	///
+	/// # Arguments
+	///
+	/// * `defer_stmt` - The defer statement
+	///
+	/// # Errors
+	///
+	/// If the deferred statement is a defer statement, it
+	/// will return an error
+	fn check_deferred_statement(
+		&self,
+		defer_stmt: &ast::DeferStmt<
+			ast::Statement<ast::Expression>,
+		>,
+	) -> SemanticCheckerResult<()>
+	{
+		let mut statement = defer_stmt.statement.as_ref();
+		while let ast::Statement::Attributed(attributed_stmt) =
+			statement
+		{
+			statement = attributed_stmt.statement.as_ref();
+		}
+		let ast::Statement::Defer(nested_defer_stmt) =
+			statement
+		else
+		{
+			return Ok(());
+		};
+
+		let mut labels: Vec<(LabelStyle, Location, String)> =
+			vec![];
+
+		if let Some(defer_token) =
+			&nested_defer_stmt.defer_token
+		{
+			labels.push((
+				LabelStyle::Primary,
+				Location::Span(defer_token.span),
+				t!("semantic-checker-error-nested-defer.label"),
+			));
+		}
+		if let Some(defer_token) = &defer_stmt.defer_token
+		{
+			labels.push((
+				LabelStyle::Secondary,
+				Location::Span(defer_token.span),
+				t!(
+					"semantic-checker-error-nested-defer.outer-label"
+				),
+			));
+		}
+
+		Err(SemanticCheckerError {
+			code: SemanticCheckerErrorCode::NestedDefer,
+			message: t!(
+				"semantic-checker-error-nested-defer.message"
+			),
+			hint: Some(t!(
+				"semantic-checker-error-nested-defer.hint"
+			)),
+			labels,
+			source_id: self.source_id.clone(),
+		})
+	}
+
+	/// Check that an attribute is known, has a valid number
+	/// of arguments and is applied to a statement it
+	/// supports
+	///
+	/// # Arguments
+	///
+	/// * `attribute` - The attribute
+	/// * `statement` - The attributed statement
+	///
+	/// # Errors
+	///
+	/// If the attribute is invalid, it will return an error
+	fn check_attribute(
+		&self,
+		attribute: &ast::Attribute<ast::Expression>,
+		statement: &ast::Statement<ast::Expression>,
+	) -> SemanticCheckerResult<()>
+	{
+		// every known attribute applies to functions and takes
+		// at most this many arguments
+		const KNOWN_ATTRIBUTES: [(&str, usize); 3] =
+			[("deprecated", 1), ("inline", 0), ("test", 0)];
+
+		let name = attribute.name().unwrap_or_default();
+		let attribute_span = attribute.get_span();
+		let create_error = |code, key: &str, hint: String| {
+			let mut labels: Vec<(LabelStyle, Location, String)> =
+				vec![];
+
+			if let Some(attribute_span) = attribute_span
+			{
+				labels.push((
+					LabelStyle::Primary,
+					Location::Span(attribute_span),
+					t!(
+						format!(
+							"semantic-checker-error-invalid-attribute.\
+							 {key}-label"
+						),
+						name = name
+					),
+				));
+			}
+
+			SemanticCheckerError {
+				code,
+				message: t!(
+					format!(
+						"semantic-checker-error-invalid-attribute.\
+						 {key}-message"
+					),
+					name = name
+				),
+				hint: Some(hint),
+				labels,
+				source_id: self.source_id.clone(),
+			}
+		};
+
+		let Some((_, max_arguments)) = KNOWN_ATTRIBUTES
+			.iter()
+			.find(|(known_name, _)| *known_name == name)
+		else
+		{
+			let known_names =
+				KNOWN_ATTRIBUTES.map(|(known_name, _)| known_name);
+			let hint = match find_similar_name(name, &known_names)
+			{
+				Some(similar_name) => t!(
+					"semantic-checker-error-invalid-attribute.\
+					 unknown-similar-hint",
+					name = similar_name
+				),
+				None => t!(
+					"semantic-checker-error-invalid-attribute.\
+					 unknown-hint",
+					names = known_names
+						.map(|known_name| format!("`@{known_name}`"))
+						.join(", ")
+				),
+			};
+			return Err(create_error(
+				SemanticCheckerErrorCode::UnknownAttribute,
+				"unknown",
+				hint,
+			));
+		};
+
+		// the arguments do not depend on the statement, so
+		// they are checked first
+		if attribute.arguments.len() > *max_arguments
+		{
+			return Err(create_error(
+				SemanticCheckerErrorCode::InvalidAttributeArguments,
+				"arguments",
+				t!(
+					"semantic-checker-error-invalid-attribute.\
+					 arguments-hint",
+					name = name,
+					count = *max_arguments
+				),
+			));
+		}
+
+		if !matches!(
+			statement,
+			ast::Statement::FunctionDeclaration(_)
+		)
+		{
+			return Err(create_error(
+				SemanticCheckerErrorCode::InvalidAttributeTarget,
+				"target",
+				t!(
+					"semantic-checker-error-invalid-attribute.\
+					 target-hint",
+					name = name
+				),
+			));
+		}
+
+		Ok(())
+	}
+
+	/// Check the condition of an if expression or an
+	/// assertion
+	///
+	/// # Arguments
+	///
+	/// * `keyword_span` - The span of the `if`, `assert` or
+	///   `static_assert` keyword
+	/// * `condition_expr` - The condition expression
+	/// * `condition` - The data type of the condition
+	/// * `kind` - The kind of the condition, `if`, `assert`
+	///   or `static-assert`
+	///
+	/// # Errors
+	///
+	/// If the condition is not a boolean, it will return an
+	/// error
+	fn check_condition(
+		&self,
+		keyword_span: Option<Span>,
+		condition_expr: &ast::Expression,
+		condition: &DataType,
+		kind: &str,
+	) -> SemanticCheckerResult<()>
+	{
+		if DataType::is_same(
+			condition,
+			&DataType::Known(KnownDataType::Bool),
+		)
+		{
+			return Ok(());
+		}
+
+		let mut labels: Vec<(LabelStyle, Location, String)> =
+			vec![];
+
+		if let Some(keyword_span) = keyword_span
+		{
+			labels.push((
+				LabelStyle::Secondary,
+				Location::Span(keyword_span),
+				t!(format!(
+					"semantic-checker-error-invalid-condition.\
+					 {kind}-label"
+				)),
+			));
+		}
+		if let Some(condition_span) = condition_expr.get_span()
+		{
+			labels.push((
+				LabelStyle::Primary,
+				Location::Span(condition_span),
+				condition.description(1, "lowercase", None, false),
+			));
+		}
+
+		Err(SemanticCheckerError {
+			code: SemanticCheckerErrorCode::InvalidCondition,
+			message: t!(
+				"semantic-checker-error-invalid-condition.message",
+				data_type =
+					condition.description(1, "lowercase", None, true)
+			),
+			hint: Some(t!(format!(
+				"semantic-checker-error-invalid-condition.\
+				 {kind}-hint"
+			))),
+			labels,
+			source_id: self.source_id.clone(),
+		})
+	}
+
+	/// Check the branches of an if expression and unify their
+	/// data types
+	///
+	/// # Arguments
+	///
+	/// * `if_expr` - The if expression
+	/// * `then_branch` - The data type of the then branch
+	/// * `else_branch` - The data type of the else branch
+	///
+	/// # Returns
+	///
+	/// The data type of the if expression
+	///
+	/// # Errors
+	///
+	/// If the branches cannot be unified, it will return an
+	/// error
+	fn check_if_branches(
+		&self,
+		if_expr: &ast::IfExpr<ast::Expression>,
+		then_branch: &DataType,
+		else_branch: &DataType,
+	) -> SemanticCheckerResult<DataType>
+	{
+		let maybe_result_data_type =
+			DataType::binary_expr_result_data_type(
+				then_branch,
+				else_branch,
+				&self.target,
+			);
+
+		if let Some(data_type) = maybe_result_data_type
+		{
+			return Ok(data_type);
+		}
+
+		let mut labels: Vec<(LabelStyle, Location, String)> =
+			vec![];
+
+		if let Some(then_span) = if_expr.then_branch.get_span()
+		{
+			labels.push((
+				LabelStyle::Secondary,
+				Location::Span(then_span),
+				t!(
+					"semantic-checker-error-incompatible-branches.\
+					 then-label",
+					data_type = then_branch.description(
+						1,
+						"lowercase",
+						None,
+						true
+					)
+				),
+			));
+		}
+		if let Some(else_span) = if_expr.else_branch.get_span()
+		{
+			labels.push((
+				LabelStyle::Primary,
+				Location::Span(else_span),
+				t!(
+					"semantic-checker-error-incompatible-branches.\
+					 else-label",
+					data_type = else_branch.description(
+						1,
+						"lowercase",
+						None,
+						true
+					)
+				),
+			));
+		}
+
+		Err(SemanticCheckerError {
+			code: SemanticCheckerErrorCode::IncompatibleBranches,
+			message: t!(
+				"semantic-checker-error-incompatible-branches.message"
+			),
+			hint: Some(t!(
+				"semantic-checker-error-incompatible-branches.hint",
+				data_type1 =
+					then_branch.description(1, "uppercase", None, true),
+				data_type2 =
+					else_branch.description(1, "lowercase", None, true)
+			)),
+			labels,
+			source_id: self.source_id.clone(),
+		})
+	}
+
+	/// Check the elements of a list expression and unify
+	/// their data types
+	///
+	/// # Arguments
+	///
+	/// * `list_expr` - The list expression
+	/// * `elements` - The annotated elements of the list
+	///
+	/// # Returns
+	///
+	/// The element data type of the list and the index of the
+	/// element that decided it
+	///
+	/// # Errors
+	///
+	/// If the list is empty or an element cannot be unified
+	/// with the elements before it, it will return an error
+	fn check_list_elements(
+		&self,
+		list_expr: &ast::ListExpr<ast::Expression>,
+		elements: &[AnnotatedExpression],
+	) -> SemanticCheckerResult<(DataType, usize)>
+	{
+		if elements.is_empty()
+		{
+			let mut labels: Vec<(LabelStyle, Location, String)> =
+				vec![];
+
+			if let Some(list_span) = list_expr.get_span()
+			{
+				labels.push((
+					LabelStyle::Primary,
+					Location::Span(list_span),
+					t!("semantic-checker-error-empty-list.label"),
+				));
+			}
+
+			return Err(SemanticCheckerError {
+				code: SemanticCheckerErrorCode::EmptyList,
+				message: t!(
+					"semantic-checker-error-empty-list.message"
+				),
+				hint: Some(t!(
+					"semantic-checker-error-empty-list.hint"
+				)),
+				labels,
+				source_id: self.source_id.clone(),
+			});
+		}
+
+		self.unify_element_data_types(
+			elements,
+			SemanticCheckerErrorCode::HeterogeneousList,
+			t!(
+				"semantic-checker-error-heterogeneous-elements.\
+				 list-message"
+			),
+		)
+	}
+
+	/// Check that the key data type of a map is hashable
+	///
+	/// # Arguments
+	///
+	/// * `key` - The key that decided the key data type
+	/// * `key_data_type` - The key data type of the map
+	///
+	/// # Errors
+	///
+	/// If the keys cannot be hashed, it will return an error
+	fn check_map_key(
+		&self,
+		key: &AnnotatedExpression,
+		key_data_type: &DataType,
+	) -> SemanticCheckerResult<()>
+	{
+		if key_data_type.is_hashable()
+		{
+			return Ok(());
+		}
+
+		let mut labels: Vec<(LabelStyle, Location, String)> =
+			vec![];
+
+		if let Some(key_span) = key.get_span()
+		{
+			labels.push((
+				LabelStyle::Primary,
+				Location::Span(key_span),
+				t!(
+					"semantic-checker-error-unhashable-key.label",
+					data_type = key_data_type.description(
+						1,
+						"lowercase",
+						None,
+						true
+					)
+				),
+			));
+		}
+
+		Err(SemanticCheckerError {
+			code: SemanticCheckerErrorCode::UnhashableKey,
+			message: t!(
+				"semantic-checker-error-unhashable-key.message",
+				data_type = key_data_type.description(
+					2,
+					"uppercase",
+					None,
+					false
+				)
+			),
+			hint: Some(t!(
+				"semantic-checker-error-unhashable-key.hint"
+			)),
+			labels,
+			source_id: self.source_id.clone(),
+		})
+	}
+
+	/// Check that the constant keys of a map are different
+	///
+	/// # Arguments
+	///
+	/// * `keys` - The annotated keys of the map
+	///
+	/// # Errors
+	///
+	/// If a key has the same value as a key before it, it
+	/// will return an error
+	fn check_duplicate_keys(
+		&self,
+		keys: &[AnnotatedExpression],
+	) -> SemanticCheckerResult<()>
+	{
+		let mut first_keys = HashMap::new();
+
+		for key in keys
+		{
+			let Some(value) =
+				Constant::evaluate_key(key, &self.target)
+			else
+			{
+				continue;
+			};
+			let Some(first_key) =
+				first_keys.insert(value.clone(), key)
+			else
+			{
+				continue;
+			};
+
+			let mut labels: Vec<(LabelStyle, Location, String)> =
+				vec![];
+			if let Some(first_span) = first_key.get_span()
+			{
+				labels.push((
+					LabelStyle::Secondary,
+					Location::Span(first_span),
+					t!(
+						"semantic-checker-error-duplicate-key.\
+						 first-label"
+					),
+				));
+			}
+			if let Some(key_span) = key.get_span()
+			{
+				labels.push((
+					LabelStyle::Primary,
+					Location::Span(key_span),
+					t!(
+						"semantic-checker-error-duplicate-key.label",
+						key = value.to_string()
+					),
+				));
+			}
+
+			return Err(SemanticCheckerError {
+				code: SemanticCheckerErrorCode::DuplicateMapKey,
+				message: t!(
+					"semantic-checker-error-duplicate-key.message",
+					key = value.to_string()
+				),
+				hint: Some(t!(
+					"semantic-checker-error-duplicate-key.hint"
+				)),
+				labels,
+				source_id: self.source_id.clone(),
+			});
+		}
+
+		Ok(())
+	}
+
+	/// Check the bounds of a range expression
+	///
+	/// # Arguments
+	///
+	/// * `range_expr` - The range expression
+	/// * `start` - The data type of the start
+	/// * `end` - The data type of the end
+	///
+	/// # Returns
+	///
+	/// The unified data type of the bounds
+	///
+	/// # Errors
+	///
+	/// If the bounds are not integers or cannot be unified,
+	/// it will return an error
+	fn check_range_bounds(
+		&self,
+		range_expr: &ast::RangeExpr<ast::Expression>,
+		start: &DataType,
+		end: &DataType,
+	) -> SemanticCheckerResult<DataType>
+	{
+		let maybe_data_type = ternary!(
+			start.is_range_bound() && end.is_range_bound(),
+			DataType::binary_expr_result_data_type(
+				start,
+				end,
+				&self.target
+			),
+			None
+		);
+
+		if let Some(data_type) = maybe_data_type
+		{
+			return Ok(data_type);
+		}
+
+		let mut labels: Vec<(LabelStyle, Location, String)> =
+			vec![];
+
+		if let Some(start_span) = range_expr.start.get_span()
+		{
+			labels.push((
+				ternary!(
+					start.is_range_bound(),
+					LabelStyle::Secondary,
+					LabelStyle::Primary
+				),
+				Location::Span(start_span),
+				t!(
+					"semantic-checker-error-invalid-range.\
+					 start-label",
+					data_type =
+						start.description(1, "lowercase", None, true)
+				),
+			));
+		}
+		if let Some(end_span) = range_expr.end.get_span()
+		{
+			labels.push((
+				ternary!(
+					end.is_range_bound() && !start.is_range_bound(),
+					LabelStyle::Secondary,
+					LabelStyle::Primary
+				),
+				Location::Span(end_span),
+				t!(
+					"semantic-checker-error-invalid-range.end-label",
+					data_type =
+						end.description(1, "lowercase", None, true)
+				),
+			));
+		}
+
+		Err(SemanticCheckerError {
+			code: SemanticCheckerErrorCode::InvalidRange,
+			message: t!(
+				"semantic-checker-error-invalid-range.message",
+				data_type1 =
+					start.description(1, "lowercase", None, true),
+				data_type2 =
+					end.description(1, "lowercase", None, true)
+			),
+			hint: Some(t!(
+				"semantic-checker-error-invalid-range.hint"
+			)),
+			labels,
+			source_id: self.source_id.clone(),
+		})
+	}
+
+	/// Check the sliced expression and the range of a slice
+	/// expression
+	///
+	/// # Arguments
+	///
+	/// * `target` - The annotated sliced expression
+	/// * `range` - The annotated range
+	///
+	/// # Returns
+	///
+	/// The element data type of the sliced expression
+	///
+	/// # Errors
+	///
+	/// If the expression is not a list or a slice, or it is
+	/// not sliced by a range, it will return an error
+	fn check_slice_operands(
+		&self,
+		target: &AnnotatedExpression,
+		range: &AnnotatedExpression,
+	) -> SemanticCheckerResult<DataType>
+	{
+		let (element_data_type, expression, kind) =
+			match (target.get_data_type(), range.get_data_type())
+			{
+				(
+					DataType::List(element)
+					| DataType::Slice(element),
+					DataType::Range(_),
+				) => return Ok(*element.clone()),
+				(DataType::List(_) | DataType::Slice(_), _) =>
+				{
+					(range.get_data_type(), range, "range")
+				}
+				_ => (target.get_data_type(), target, "target"),
+			};
+
+		let mut labels: Vec<(LabelStyle, Location, String)> =
+			vec![];
+
+		if let Some(span) = expression.get_span()
+		{
+			labels.push((
+				LabelStyle::Primary,
+				Location::Span(span),
+				t!(
+					format!(
+						"semantic-checker-error-invalid-slice.\
+						 {kind}-label"
+					),
+					data_type = element_data_type.description(
+						1,
+						"lowercase",
+						None,
+						true
+					)
+				),
+			));
+		}
+
+		Err(SemanticCheckerError {
+			code: SemanticCheckerErrorCode::InvalidSlice,
+			message: t!(
+				format!(
+					"semantic-checker-error-invalid-slice.\
+					 {kind}-message"
+				),
+				data_type = element_data_type.description(
+					1,
+					"lowercase",
+					None,
+					true
+				)
+			),
+			hint: Some(t!(format!(
+				"semantic-checker-error-invalid-slice.{kind}-hint"
+			))),
+			labels,
+			source_id: self.source_id.clone(),
+		})
+	}
+
+	/// Check that a slice with constant bounds stays inside
+	/// of the sliced list. The length is only known for list
+	/// literals and slices of them with constant bounds,
+	/// other lists are checked when the program runs.
+	///
+	/// # Arguments
+	///
+	/// * `target` - The annotated sliced expression
+	/// * `range` - The annotated range
+	///
+	/// # Errors
+	///
+	/// If the range is negative, reversed or ends past the
+	/// end of the list, it will return an error
+	fn check_slice_bounds(
+		&self,
+		target: &AnnotatedExpression,
+		range: &AnnotatedExpression,
+	) -> SemanticCheckerResult<()>
+	{
+		let Some((start, end)) =
+			Constant::evaluate_range(range, &self.target)
+		else
+		{
+			return Ok(());
+		};
+		let length =
+			Constant::evaluate_length(target, &self.target);
+
+		let message_key = if start.is_negative()
+		{
+			"negative-message"
+		}
+		else if start > end
+		{
+			"reversed-message"
+		}
+		else if length
+			.is_some_and(|length| end > BigInt::from(length))
+		{
+			"past-end-message"
+		}
+		else
+		{
+			return Ok(());
+		};
+
+		let mut labels: Vec<(LabelStyle, Location, String)> =
+			vec![];
+
+		if let Some(range_span) = range.get_span()
+		{
+			labels.push((
+				LabelStyle::Primary,
+				Location::Span(range_span),
+				t!(
+					"semantic-checker-error-slice-out-of-bounds.\
+					 range-label",
+					start = start.to_string(),
+					end = end.to_string()
+				),
+			));
+		}
+		if let (Some(length), Some(target_span)) =
+			(length, target.get_span())
+		{
+			labels.push((
+				LabelStyle::Secondary,
+				Location::Span(target_span),
+				t!(
+					"semantic-checker-error-slice-out-of-bounds.\
+					 list-label",
+					length = length
+				),
+			));
+		}
+
+		Err(SemanticCheckerError {
+			code: SemanticCheckerErrorCode::SliceOutOfBounds,
+			message: t!(
+				format!(
+					"semantic-checker-error-slice-out-of-bounds.\
+					 {message_key}"
+				),
+				start = start.to_string(),
+				end = end.to_string(),
+				length = length.unwrap_or_default()
+			),
+			hint: Some(t!(
+				"semantic-checker-error-slice-out-of-bounds.hint"
+			)),
+			labels,
+			source_id: self.source_id.clone(),
+		})
+	}
+
+	/// Unify the data types of the elements of a list or the
+	/// keys or values of a map
+	///
+	/// # Arguments
+	///
+	/// * `elements` - The annotated elements, at least one
+	/// * `code` - The error code for incompatible elements
+	/// * `message` - The error message for incompatible
+	///   elements
+	///
+	/// # Returns
+	///
+	/// The unified data type and the index of the element
+	/// that decided it
+	///
+	/// # Errors
+	///
+	/// If an element cannot be unified with the elements
+	/// before it, it will return an error
+	fn unify_element_data_types(
+		&self,
+		elements: &[AnnotatedExpression],
+		code: SemanticCheckerErrorCode,
+		message: String,
+	) -> SemanticCheckerResult<(DataType, usize)>
+	{
+		let mut data_type = elements[0].get_data_type().clone();
+		let mut deciding_index = 0;
+
+		for (index, element) in
+			elements.iter().enumerate().skip(1)
+		{
+			let element_data_type = element.get_data_type();
+			if let Some(result_data_type) =
+				DataType::binary_expr_result_data_type(
+					&data_type,
+					element_data_type,
+					&self.target,
+				)
+			{
+				if result_data_type != data_type
+				{
+					deciding_index = index;
+					data_type = result_data_type;
+				}
+				continue;
+			}
+
+			// the first conflicting element is reported
+			let mut labels: Vec<(LabelStyle, Location, String)> =
+				vec![];
+
+			if let Some(deciding_span) =
+				elements[deciding_index].get_span()
+			{
+				labels.push((
+					LabelStyle::Secondary,
+					Location::Span(deciding_span),
+					t!(
+						"semantic-checker-error-heterogeneous-elements.\
+						 first-label",
+						data_type = data_type.description(
+							1,
+							"lowercase",
+							None,
+							true
+						)
+					),
+				));
+			}
+			if let Some(element_span) = element.get_span()
+			{
+				labels.push((
+					LabelStyle::Primary,
+					Location::Span(element_span),
+					t!(
+						"semantic-checker-error-heterogeneous-elements.\
+						 element-label",
+						data_type = element_data_type.description(
+							1,
+							"lowercase",
+							None,
+							true
+						)
+					),
+				));
+			}
+
+			return Err(SemanticCheckerError {
+				code,
+				message,
+				hint: Some(t!(
+					"semantic-checker-error-heterogeneous-elements.\
+					 hint",
+					data_type1 = data_type.description(
+						1,
+						"uppercase",
+						None,
+						true
+					),
+					data_type2 = element_data_type.description(
+						1,
+						"lowercase",
+						None,
+						true
+					)
+				)),
+				labels,
+				source_id: self.source_id.clone(),
+			});
+		}
+
+		Ok((data_type, deciding_index))
+	}
+
+	/// Convert the untyped elements of a list or the keys or
+	/// values of a map to their unified data type
+	///
+	/// # Arguments
+	///
+	/// * `elements` - The annotated elements
+	/// * `data_type` - The unified data type
+	/// * `deciding_index` - The index of the element that
+	///   decided the data type
+	///
+	/// # Returns
+	///
+	/// The converted elements
+	///
+	/// # Errors
+	///
+	/// If the value of an untyped element does not fit in the
+	/// data type, it will return an error
+	fn convert_elements(
+		&mut self,
+		elements: Vec<AnnotatedExpression>,
+		data_type: &DataType,
+		deciding_index: usize,
+	) -> SemanticCheckerResult<Vec<AnnotatedExpression>>
+	{
+		// untyped elements take the data type of the element
+		// that decided it
+		let context_span = elements[deciding_index].get_span();
+		let mut converted_elements = vec![];
+		for element in elements
+		{
+			self.check_portable_conversion(
+				&element,
+				data_type,
+				context_span,
+			);
+			converted_elements.push(self.convert_untyped_expr(
+				element,
+				data_type,
+				context_span,
+			)?);
+		}
+
+		Ok(converted_elements)
+	}
+
+	/// Convert an untyped expression to the data type of its
+	/// context. Constant expressions are evaluated first and
+	/// their value must fit in the data type.
+	///
+	/// # Arguments
+	///
+	/// * `expression` - The annotated expression
+	/// * `data_type` - The data type of the context
+	/// * `context_span` - The span of the expression that
+	///   decides the data type
+	///
+	/// # Returns
+	///
+	/// The expression annotated with the data type. Typed
+	/// expressions are returned as is.
+	///
+	/// # Errors
+	///
+	/// If the value of the constant does not fit in the data
+	/// type, it will return an error
+	fn convert_untyped_expr(
+		&self,
+		expression: AnnotatedExpression,
+		data_type: &DataType,
+		context_span: Option<Span>,
+	) -> SemanticCheckerResult<AnnotatedExpression>
+	{
+		let source = expression.get_data_type();
+		if !(source.is_untyped() || source.contains_untyped())
+			|| source == data_type
+		{
+			return Ok(expression);
+		}
+
+		match Constant::try_evaluate(&expression)
+		{
+			Ok(constant) =>
+			{
+				if !constant.fits_in(data_type, &self.target)
+				{
+					return Err(self.create_constant_overflow_error(
+						constant.to_string(),
+						expression.get_span(),
+						data_type,
+						context_span,
+					));
+				}
+				return Ok(
+					self.retype_untyped_expr(expression, data_type),
+				);
+			}
+			// the expression can't be evaluated at compile time,
+			// so its constant parts are converted one by one
+			Err(error)
+				if error.code == ConstantErrorCode::NotConstant =>
+			{}
+			// bigint operations don't overflow when the program
+			// runs, so their operands are converted one by one
+			Err(error)
+				if error.code == ConstantErrorCode::Overflow
+					&& data_type.is_big_integer() =>
+			{}
+			Err(error) =>
+			{
+				return Err(
+					self
+						.create_constant_error(error.code, error.span),
+				);
+			}
+		}
+
+		Ok(match expression
+		{
+			// only bigints get here, they hold any integer
+			// literal
+			AnnotatedExpression::Literal(mut literal) =>
+			{
+				literal.data_type = data_type.clone();
+				AnnotatedExpression::Literal(literal)
+			}
+			AnnotatedExpression::Group(mut group) =>
+			{
+				group.inner.expression =
+					Box::new(self.convert_untyped_expr(
+						*group.inner.expression,
+						data_type,
+						context_span,
+					)?);
+				group.data_type = data_type.clone();
+				AnnotatedExpression::Group(group)
+			}
+			AnnotatedExpression::Unary(mut unary) =>
+			{
+				unary.inner.right =
+					Box::new(self.convert_untyped_expr(
+						*unary.inner.right,
+						data_type,
+						context_span,
+					)?);
+				unary.data_type = data_type.clone();
+				unary.overflow_policy =
+					self.overflow_policy_for(data_type);
+				AnnotatedExpression::Unary(unary)
+			}
+			AnnotatedExpression::Binary(mut binary) =>
+			{
+				binary.inner.left =
+					Box::new(self.convert_untyped_expr(
+						*binary.inner.left,
+						data_type,
+						context_span,
+					)?);
+				binary.inner.right =
+					Box::new(self.convert_untyped_expr(
+						*binary.inner.right,
+						data_type,
+						context_span,
+					)?);
+				binary.data_type = data_type.clone();
+				binary.overflow_policy =
+					self.overflow_policy_for(data_type);
+				AnnotatedExpression::Binary(binary)
+			}
+			AnnotatedExpression::Block(mut block) =>
+			{
+				block.inner.expression =
+					Box::new(self.convert_untyped_expr(
+						*block.inner.expression,
+						data_type,
+						context_span,
+					)?);
+				block.data_type = data_type.clone();
+				AnnotatedExpression::Block(block)
+			}
+			AnnotatedExpression::If(mut if_expr) =>
+			{
+				if_expr.inner.then_branch =
+					Box::new(self.convert_untyped_expr(
+						*if_expr.inner.then_branch,
+						data_type,
+						context_span,
+					)?);
+				if_expr.inner.else_branch =
+					Box::new(self.convert_untyped_expr(
+						*if_expr.inner.else_branch,
+						data_type,
+						context_span,
+					)?);
+				if_expr.data_type = data_type.clone();
+				AnnotatedExpression::If(if_expr)
+			}
+			AnnotatedExpression::List(mut list) =>
+			{
+				// the elements take the element data type
+				if let DataType::List(element_data_type) = data_type
+				{
+					list.inner.elements = list
+						.inner
+						.elements
+						.into_iter()
+						.map(|element| {
+							self.convert_untyped_expr(
+								element,
+								element_data_type,
+								context_span,
+							)
+						})
+						.collect::<SemanticCheckerResult<_>>()?;
+					list.data_type = data_type.clone();
+				}
+				AnnotatedExpression::List(list)
+			}
+			AnnotatedExpression::Map(mut map) =>
+			{
+				// the keys and values take the key and value
+				// data types
+				if let DataType::Map(
+					key_data_type,
+					value_data_type,
+				) = data_type
+				{
+					map.inner.entries = map
+						.inner
+						.entries
+						.into_iter()
+						.map(|entry| {
+							Ok(MapEntry {
+								key: self.convert_untyped_expr(
+									entry.key,
+									key_data_type,
+									context_span,
+								)?,
+								colon_token: entry.colon_token,
+								value: self.convert_untyped_expr(
+									entry.value,
+									value_data_type,
+									context_span,
+								)?,
+							})
+						})
+						.collect::<SemanticCheckerResult<_>>()?;
+					map.data_type = data_type.clone();
+				}
+				AnnotatedExpression::Map(map)
+			}
+			AnnotatedExpression::Range(mut range) =>
+			{
+				// the bounds take the bound data type
+				if let DataType::Range(bound_data_type) = data_type
+				{
+					range.inner.start =
+						Box::new(self.convert_untyped_expr(
+							*range.inner.start,
+							bound_data_type,
+							context_span,
+						)?);
+					range.inner.end =
+						Box::new(self.convert_untyped_expr(
+							*range.inner.end,
+							bound_data_type,
+							context_span,
+						)?);
+					range.data_type = data_type.clone();
+				}
+				AnnotatedExpression::Range(range)
+			}
+			AnnotatedExpression::Slice(mut slice) =>
+			{
+				// the sliced list takes the list data type
+				if let DataType::Slice(element_data_type) =
+					data_type
+				{
+					slice.inner.target =
+						Box::new(self.convert_untyped_expr(
+							*slice.inner.target,
+							&DataType::List(element_data_type.clone()),
+							context_span,
+						)?);
+					slice.data_type = data_type.clone();
+				}
+				AnnotatedExpression::Slice(slice)
+			}
+		})
+	}
+
+	/// Convert an untyped expression without a context data
+	/// type to the default data type, `int64` for integers
+	/// and `double` for floats
+	///
+	/// # Arguments
+	///
+	/// * `expression` - The annotated expression
+	///
+	/// # Returns
+	///
+	/// The expression annotated with the default data type.
+	/// Typed expressions are returned as is.
+	///
+	/// # Errors
+	///
+	/// If the value of the constant does not fit in the
+	/// default data type, it will return an error
+	fn default_untyped_expr(
+		&self,
+		expression: AnnotatedExpression,
+	) -> SemanticCheckerResult<AnnotatedExpression>
+	{
+		let data_type =
+			expression.get_data_type().default_data_type();
+		self.convert_untyped_expr(expression, &data_type, None)
+	}
+
+	/// Annotate an untyped constant expression and all of its
+	/// untyped sub-expressions with the data type
+	///
+	/// # Arguments
+	///
+	/// * `expression` - The annotated constant expression
+	/// * `data_type` - The data type of the context
+	///
+	/// # Returns
+	///
+	/// The expression annotated with the data type
+	fn retype_untyped_expr(
+		&self,
+		expression: AnnotatedExpression,
+		data_type: &DataType,
+	) -> AnnotatedExpression
+	{
+		if !expression.get_data_type().is_untyped()
+		{
+			return expression;
+		}
+
+		match expression
+		{
+			AnnotatedExpression::Literal(mut literal) =>
+			{
+				literal.data_type = data_type.clone();
+				AnnotatedExpression::Literal(literal)
+			}
+			AnnotatedExpression::Group(mut group) =>
+			{
+				group.inner.expression =
+					Box::new(self.retype_untyped_expr(
+						*group.inner.expression,
+						data_type,
+					));
+				group.data_type = data_type.clone();
+				AnnotatedExpression::Group(group)
+			}
+			AnnotatedExpression::Unary(mut unary) =>
+			{
+				unary.inner.right =
+					Box::new(self.retype_untyped_expr(
+						*unary.inner.right,
+						data_type,
+					));
+				unary.data_type = data_type.clone();
+				unary.overflow_policy =
+					self.overflow_policy_for(data_type);
+				AnnotatedExpression::Unary(unary)
+			}
+			AnnotatedExpression::Binary(mut binary) =>
+			{
+				binary.inner.left =
+					Box::new(self.retype_untyped_expr(
+						*binary.inner.left,
+						data_type,
+					));
+				binary.inner.right =
+					Box::new(self.retype_untyped_expr(
+						*binary.inner.right,
+						data_type,
+					));
+				binary.data_type = data_type.clone();
+				binary.overflow_policy =
+					self.overflow_policy_for(data_type);
+				AnnotatedExpression::Binary(binary)
+			}
+			// blocks and if expressions are never constants
+			expression => expression,
+		}
+	}
+
+	/// Get the overflow policy of an arithmetic operation
+	///
+	/// # Arguments
+	///
+	/// * `data_type` - The data type of the operation
+	///
+	/// # Returns
+	///
+	/// The configured policy for fixed width and untyped
+	/// integers, which get their data type later. `None` for
+	/// bigints and floating point, which never overflow.
+	fn overflow_policy_for(
+		&self,
+		data_type: &DataType,
+	) -> Option<OverflowPolicy>
+	{
+		ternary!(
+			data_type.is_generic_integer()
+				|| data_type
+					== &DataType::Known(KnownDataType::UntypedInt),
+			Some(self.overflow_policy),
+			None
+		)
+	}
+
+	/// Warn if converting the expression to the data type
+	/// relies on the width of `int` or `uint` of the target
+	///
+	/// # Arguments
+	///
+	/// * `expression` - The annotated expression
+	/// * `data_type` - The data type it is converted to
+	/// * `context_span` - The span of the expression that
+	///   decides the data type
+	fn check_portable_conversion(
+		&mut self,
+		expression: &AnnotatedExpression,
+		data_type: &DataType,
+		context_span: Option<Span>,
+	)
+	{
+		let source = expression.get_data_type();
+		if !source.is_target_dependent()
+			&& !data_type.is_target_dependent()
+		{
+			return;
+		}
+
+		let is_portable = if source.is_untyped()
+		{
+			// the value of a constant has to fit on every
+			// target, not only on the current one
+			Constant::evaluate(expression).is_none_or(
+				|constant| {
+					Target::POINTER_WIDTHS.iter().all(
+						|pointer_width| {
+							constant.fits_in(
+								data_type,
+								&self
+									.target
+									.with_pointer_width(*pointer_width),
+							)
+						},
+					)
+				},
+			)
+		}
+		else
+		{
+			DataType::is_portable_cast(
+				source,
+				data_type,
+				&self.target,
+			)
+		};
+
+		if !is_portable
+		{
+			let warning = self
+				.create_non_portable_int_width_warning(
+					expression,
+					data_type,
+					context_span,
+				);
+			self.warnings.push(warning);
+		}
+	}
+
+	/// Create an error for an untyped constant that does not
+	/// fit in the data type of its context
+	///
+	/// # Arguments
+	///
+	/// * `value` - The value of the constant
+	/// * `span` - The span of the constant
+	/// * `data_type` - The data type of the context
+	/// * `context_span` - The span of the expression that
+	///   decides the data type
+	///
+	/// # Returns
+	///
+	/// The semantic checker error
+	fn create_constant_overflow_error(
+		&self,
+		value: String,
+		span: Option<Span>,
+		data_type: &DataType,
+		context_span: Option<Span>,
+	) -> SemanticCheckerError
+	{
+		let mut labels: Vec<(LabelStyle, Location, String)> =
+			vec![];
+
+		if let Some(context_span) = context_span
+		{
+			labels.push((
+				LabelStyle::Secondary,
+				Location::Span(context_span),
+				t!(
+					"semantic-checker-error-constant-overflow.\
+					 context-label",
+					data_type = data_type.description(
+						1,
+						"lowercase",
+						None,
+						true
+					)
+				),
+			));
+		}
+		if let Some(span) = span
+		{
+			labels.push((
+				LabelStyle::Primary,
+				Location::Span(span),
+				t!(
+					"semantic-checker-error-constant-overflow.\
+					 constant-label",
+					value = value.clone()
+				),
+			));
+		}
+
+		SemanticCheckerError {
+			code: SemanticCheckerErrorCode::ConstantOverflow,
+			message: t!(
+				"semantic-checker-error-constant-overflow.message",
+				value = value,
+				data_type =
+					data_type.description(1, "lowercase", None, true)
+			),
+			hint: Some(t!(
+				"semantic-checker-error-constant-overflow.hint",
+				data_type =
+					data_type.description(1, "lowercase", None, true)
+			)),
+			labels,
+			source_id: self.source_id.clone(),
+		}
+	}
+
+	/// Create an error for a constant expression that can't
+	/// be evaluated
+	///
+	/// # Arguments
+	///
+	/// * `code` - The reason it can't be evaluated
+	/// * `span` - The span of the operation that has no value
+	///
+	/// # Returns
+	///
+	/// The semantic checker error
+	fn create_constant_error(
+		&self,
+		code: ConstantErrorCode,
+		span: Option<Span>,
+	) -> SemanticCheckerError
+	{
+		let (code, key) = match code
+		{
+			ConstantErrorCode::DivisionByZero => (
+				SemanticCheckerErrorCode::InvalidConstantOperation,
+				"semantic-checker-error-invalid-constant-operation.\
+				 division-by-zero",
+			),
+			ConstantErrorCode::NegativeExponent => (
+				SemanticCheckerErrorCode::InvalidConstantOperation,
+				"semantic-checker-error-invalid-constant-operation.\
+				 negative-exponent",
+			),
+			ConstantErrorCode::Overflow
+			| ConstantErrorCode::NotConstant => (
+				SemanticCheckerErrorCode::ConstantOverflow,
+				"semantic-checker-error-constant-overflow.evaluation",
+			),
+		};
+
+		let mut labels: Vec<(LabelStyle, Location, String)> =
+			vec![];
+		if let Some(span) = span
+		{
+			labels.push((
+				LabelStyle::Primary,
+				Location::Span(span),
+				t!(format!("{key}-label")),
+			));
+		}
+
+		SemanticCheckerError {
+			code,
+			message: t!(format!("{key}-message")),
+			hint: Some(t!(
+				format!("{key}-hint"),
+				max_bits = MAX_INT_BITS
+			)),
+			labels,
+			source_id: self.source_id.clone(),
+		}
+	}
+
+	/// Create a warning for a conversion that relies on the
+	/// width of `int` or `uint`
+	///
+	/// # Arguments
+	///
+	/// * `expression` - The converted expression
+	/// * `data_type` - The data type it is converted to
+	/// * `context_span` - The span of the expression that
+	///   decides the data type
+	///
+	/// # Returns
+	///
+	/// The warning
+	fn create_non_portable_int_width_warning(
+		&self,
+		expression: &AnnotatedExpression,
+		data_type: &DataType,
+		context_span: Option<Span>,
+	) -> SemanticCheckerError
+	{
+		let source = expression.get_data_type();
+		let int_data_type = ternary!(
+			data_type.is_target_dependent(),
+			data_type,
+			source
+		);
+		let mut labels: Vec<(LabelStyle, Location, String)> =
+			vec![];
+
+		if let Some(context_span) = context_span
+		{
+			labels.push((
+				LabelStyle::Secondary,
+				Location::Span(context_span),
+				t!(
+					"semantic-checker-warning-non-portable-int-width.\
+					 context-label",
+					data_type = data_type.description(
+						1,
+						"lowercase",
+						None,
+						true
+					)
+				),
+			));
+		}
+		if let Some(span) = expression.get_span()
+		{
+			labels.push((
+				LabelStyle::Primary,
+				Location::Span(span),
+				t!(
+					"semantic-checker-warning-non-portable-int-width.\
+					 label",
+					data_type = source.description(
+						1,
+						"lowercase",
+						None,
+						true
+					)
+				),
+			));
+		}
+
+		SemanticCheckerError {
+			code: SemanticCheckerErrorCode::NonPortableIntWidth,
+			message: t!(
+				"semantic-checker-warning-non-portable-int-width.\
+				 message",
+				data_type = int_data_type.to_string(),
+				bit_size = int_data_type.get_bit_size(&self.target),
+				target = self.target.name.clone()
+			),
+			hint: Some(t!(
+				"semantic-checker-warning-non-portable-int-width.\
+				 hint",
+				data_type = int_data_type.to_string(),
+				data_type1 =
+					format!("{}32", int_data_type.to_string()),
+				data_type2 =
+					format!("{}64", int_data_type.to_string())
+			)),
+			labels,
+			source_id: self.source_id.clone(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::common::config::{
+		CompilerModeConfig,
+		GlobalConfig,
+		InnerConfig,
+	};
+	use crate::parser::Parser;
+	use crate::parser::lexer::Lexer;
+
+	/// Result of checking a module
+	type CheckResult = SemanticCheckerResult<(
+		AnnotatedModule<AnnotatedExpression>,
+		Vec<SemanticCheckerError>,
+	)>;
+
+	/// Check the source code with the configuration
+	fn check_with_config(
+		source_code: &str,
+		config: CompilerModeConfig,
+	) -> CheckResult
+	{
+		let tokens = Lexer::tokenize(
+			"string".into(),
+			source_code.to_owned(),
+		)
+		.unwrap();
+		let module =
+			Parser::parse("string".into(), false, tokens)
+				.unwrap();
+		let config = Config::new(
+			GlobalConfig::default(),
+			InnerConfig::Compiler(config),
+		);
+		SemanticChecker::check("string".into(), module, &config)
+	}
+
+	/// Check the source code for a 64-bit target
+	fn check(source_code: &str) -> CheckResult
+	{
+		check_with_config(
+			source_code,
+			CompilerModeConfig {
+				target: Target::from("x86_64".to_owned()),
+				..Default::default()
+			},
+		)
+	}
+
+	/// Check the source code and get the expression of its
+	/// last expression or echo statement
+	fn check_expression(
+		source_code: &str,
+	) -> AnnotatedExpression
+	{
+		let (mut module, _) = check(source_code).unwrap();
+		match module.statements.pop()
+		{
+			Some(AnnotatedStatement::Expression(stmt)) =>
+			{
+				stmt.inner.expression
+			}
+			Some(AnnotatedStatement::Echo(stmt)) =>
+			{
+				stmt.inner.expression
+			}
+			statement => panic!(
+				"expected an expression or echo statement, found \
+				 {:?}",
+				statement
+			),
+		}
+	}
+
+	/// Check the source code and get the code of its error
+	fn check_error_code(
+		source_code: &str,
+	) -> SemanticCheckerErrorCode
+	{
+		check(source_code).unwrap_err().code
+	}
+
+	#[test]
+	fn test_check_block_expr()
+	{
+		let block = check_expression("{ echo 1u8; 2u16 }");
+		assert_eq!(
+			block.get_data_type(),
+			&DataType::Known(KnownDataType::UInt16)
+		);
+	}
+
+	#[test]
+	fn test_check_if_expr()
+	{
+		let AnnotatedExpression::If(if_expr) =
+			check_expression("if true then 1u8 else 2")
+		else
+		{
+			panic!("expected an if expression");
+		};
+		let uint8 = DataType::Known(KnownDataType::UInt8);
+		assert_eq!(if_expr.data_type, uint8);
+		// the untyped branch takes the data type of the other
+		// branch
+		assert_eq!(
+			if_expr.inner.else_branch.get_data_type(),
+			&uint8
+		);
+	}
+
+	#[test]
+	fn test_check_if_expr_errors()
+	{
+		assert_eq!(
+			check_error_code("if 1u8 then 1 else 2"),
+			SemanticCheckerErrorCode::InvalidCondition
+		);
+		assert_eq!(
+			check_error_code("if true then 1u8 else false"),
+			SemanticCheckerErrorCode::IncompatibleBranches
+		);
+		assert_eq!(
+			check_error_code("if true then { 1u8 } else -1i8"),
+			SemanticCheckerErrorCode::IncompatibleBranches
+		);
+	}
+
+	#[test]
+	fn test_check_untyped_constants()
+	{
+		let uint8 = DataType::Known(KnownDataType::UInt8);
+		let int64 = DataType::Known(KnownDataType::Int64);
+		let double = DataType::Known(KnownDataType::Double);
+
+		// untyped operands take the data type of the other
+		// operand
+		let AnnotatedExpression::Binary(binary) =
+			check_expression("1u8 + 255")
+		else
+		{
+			panic!("expected a binary expression");
+		};
+		assert_eq!(binary.data_type, uint8);
+		assert_eq!(binary.inner.right.get_data_type(), &uint8);
+
+		// constants without a context take the default data
+		// types
+		assert_eq!(
+			check_expression("1 + 2").get_data_type(),
+			&int64
+		);
+		assert_eq!(
+			check_expression("echo 1.5 * 2").get_data_type(),
+			&double
+		);
+		assert_eq!(
+			check_expression("{ 1 }").get_data_type(),
+			&int64
+		);
+		assert_eq!(
+			check_expression("echo [1, 2]").get_data_type(),
+			&DataType::List(Box::new(int64))
+		);
+
+		// only the final value has to fit, and bigints compute
+		// when the program runs
+		assert_eq!(
+			check_expression("300 - 100 + 1u8").get_data_type(),
+			&uint8
+		);
+		assert_eq!(
+			check_expression("echo 10 ** 40 + 1n")
+				.get_data_type(),
+			&DataType::Known(KnownDataType::BigInt)
+		);
+		assert_eq!(
+			check_expression("echo 10 ** 100000 + 1n")
+				.get_data_type(),
+			&DataType::Known(KnownDataType::BigInt)
+		);
+
+		// untyped integers are exact, so every value of 128-bit
+		// integers can be folded
+		assert_eq!(
+			check_expression(
+				"340282366920938463463374607431768211455 + 0u128"
+			)
+			.get_data_type(),
+			&DataType::Known(KnownDataType::UInt128)
+		);
+		assert_eq!(
+			check_expression(
+				"-170141183460469231731687303715884105728 + 0i128"
+			)
+			.get_data_type(),
+			&DataType::Known(KnownDataType::Int128)
+		);
+	}
+
+	#[test]
+	fn test_check_untyped_constant_errors()
+	{
+		for source_code in &[
+			"1u8 + 256",
+			"-1 + 0u8",
+			"echo 10000000000000000000",
+			"echo 10 ** 40",
+			"echo 10 ** 40 + 1u8",
+			"echo 10 ** 100000",
+			"340282366920938463463374607431768211455 + 1 + 0u128",
+			"echo 1e300 * 1e300",
+		]
+		{
+			assert_eq!(
+				check_error_code(source_code),
+				SemanticCheckerErrorCode::ConstantOverflow,
+				"{}",
+				source_code
+			);
+		}
+
+		for source_code in &[
+			"echo 1 / 0 + 1u8",
+			"echo 1 % 0",
+			"echo 1.5 / 0.0",
+			"echo 2 ** -1 + 1u8",
+		]
+		{
+			assert_eq!(
+				check_error_code(source_code),
+				SemanticCheckerErrorCode::InvalidConstantOperation,
+				"{}",
+				source_code
+			);
+		}
+	}
+
+	#[test]
+	fn test_check_integer_and_float_operands()
+	{
+		assert_eq!(
+			check_expression("1i16 + 0.5f32").get_data_type(),
+			&DataType::Known(KnownDataType::Float32)
+		);
+		assert_eq!(
+			check_expression(
+				"-170141183460469231731687303715884105728i128 + 1"
+			)
+			.get_data_type(),
+			&DataType::Known(KnownDataType::Int128)
+		);
+
+		// the float would round or overflow to `inf`
+		for source_code in &[
+			"340282366920938463463374607431768211455u128 + 0f32",
+			"-170141183460469231731687303715884105728i128 + 0f32",
+			"1i64 + 0f16",
+			"1u64 + 0f16",
+		]
+		{
+			assert_eq!(
+				check_error_code(source_code),
+				SemanticCheckerErrorCode::InvalidOperand,
+				"{}",
+				source_code
+			);
+		}
+	}
+
+	#[test]
+	fn test_check_overflow_policy()
+	{
+		let overflow_policy = |source_code: &str| {
+			let (mut module, _) = check_with_config(
+				source_code,
+				CompilerModeConfig {
+					overflow_policy: OverflowPolicy::Wrapping,
+					..Default::default()
+				},
+			)
+			.unwrap();
+			match module.statements.pop()
+			{
+				Some(AnnotatedStatement::Expression(stmt)) =>
+				{
+					match stmt.inner.expression
+					{
+						AnnotatedExpression::Binary(binary) =>
+						{
+							binary.overflow_policy
+						}
+						AnnotatedExpression::Unary(unary) =>
+						{
+							unary.overflow_policy
+						}
+						expression => panic!(
+							"expected an arithmetic expression, found \
+							 {:?}",
+							expression
+						),
+					}
+				}
+				statement => panic!(
+					"expected an expression statement, found {:?}",
+					statement
+				),
+			}
+		};
+
+		let wrapping = Some(OverflowPolicy::Wrapping);
+		assert_eq!(overflow_policy("1u8 + 2"), wrapping);
+		assert_eq!(overflow_policy("-(1i8)"), wrapping);
+		// constants without a context become int64
+		assert_eq!(overflow_policy("1 + 2"), wrapping);
+		assert_eq!(overflow_policy("-1"), wrapping);
+		// bigints and floats never overflow
+		assert_eq!(overflow_policy("1n + 2"), None);
+		assert_eq!(overflow_policy("-1n"), None);
+		assert_eq!(overflow_policy("1.5 * 2"), None);
+		assert_eq!(overflow_policy("-1.5f32"), None);
+	}
+
+	#[test]
+	fn test_check_list_expr()
+	{
+		let uint8 = DataType::Known(KnownDataType::UInt8);
+		let list_uint8 =
+			DataType::List(Box::new(uint8.clone()));
+
+		let AnnotatedExpression::List(list) =
+			check_expression("[1u8, 2, 3]")
+		else
+		{
+			panic!("expected a list expression");
+		};
+		assert_eq!(list.data_type, list_uint8);
+		// untyped elements take the element data type
+		assert_eq!(
+			list.inner.elements[2].get_data_type(),
+			&uint8
+		);
+
+		assert_eq!(
+			check_expression("[[1], [2u8]]").get_data_type(),
+			&DataType::List(Box::new(list_uint8))
+		);
+		assert_eq!(
+			check_expression("[1, 2.5]").get_data_type(),
+			&DataType::List(Box::new(DataType::Known(
+				KnownDataType::Double
+			)))
+		);
+	}
+
+	#[test]
+	fn test_check_list_expr_errors()
+	{
+		assert_eq!(
+			check_error_code("[1u8, true]"),
+			SemanticCheckerErrorCode::HeterogeneousList
+		);
+		assert_eq!(
+			check_error_code("[[1u8], [true]]"),
+			SemanticCheckerErrorCode::HeterogeneousList
+		);
+		assert_eq!(
+			check_error_code("[]"),
+			SemanticCheckerErrorCode::EmptyList
+		);
+		assert_eq!(
+			check_error_code("[1u8, 256]"),
+			SemanticCheckerErrorCode::ConstantOverflow
+		);
+	}
+
+	#[test]
+	fn test_check_map_expr()
+	{
+		let AnnotatedExpression::Map(map) =
+			check_expression("{1: 1.5, 2u16: 2}")
+		else
+		{
+			panic!("expected a map expression");
+		};
+		let uint16 = DataType::Known(KnownDataType::UInt16);
+		let double = DataType::Known(KnownDataType::Double);
+		assert_eq!(
+			map.data_type,
+			DataType::Map(
+				Box::new(uint16.clone()),
+				Box::new(double.clone())
+			)
+		);
+		// untyped keys and values take the key and value data
+		// types
+		assert_eq!(
+			map.inner.entries[0].key.get_data_type(),
+			&uint16
+		);
+		assert_eq!(
+			map.inner.entries[1].value.get_data_type(),
+			&double
+		);
+
+		assert_eq!(
+			check_expression("{true: [1u8], false: [2]}")
+				.get_data_type(),
+			&DataType::Map(
+				Box::new(DataType::Known(KnownDataType::Bool)),
+				Box::new(DataType::List(Box::new(
+					DataType::Known(KnownDataType::UInt8)
+				)))
+			)
+		);
+	}
+
+	#[test]
+	fn test_check_map_expr_errors()
+	{
+		assert_eq!(
+			check_error_code("{1u8: 1, true: 2}"),
+			SemanticCheckerErrorCode::HeterogeneousMap
+		);
+		assert_eq!(
+			check_error_code("{1: 1u8, 2: true}"),
+			SemanticCheckerErrorCode::HeterogeneousMap
+		);
+		assert_eq!(
+			check_error_code("{1.5: 1}"),
+			SemanticCheckerErrorCode::UnhashableKey
+		);
+		assert_eq!(
+			check_error_code("{[1]: 1}"),
+			SemanticCheckerErrorCode::UnhashableKey
+		);
+		assert_eq!(
+			check_error_code("{1u8: 1, 256: 2}"),
+			SemanticCheckerErrorCode::ConstantOverflow
+		);
+
+		// keys are compared by their values
+		for source_code in &[
+			"{1: 1, 1: 2}",
+			"{1u8: 1, 0x1: 2}",
+			"{1: 1, 2: 2, 1 + 1: 3}",
+			"{true: 1, false: 2, (true): 3}",
+			"{if true then 1 else 2: 1, 1: 2}",
+		]
+		{
+			assert_eq!(
+				check_error_code(source_code),
+				SemanticCheckerErrorCode::DuplicateMapKey,
+				"{}",
+				source_code
+			);
+		}
+
+		let error = check("{1: 1, 2: 2, 1: 3}").unwrap_err();
+		assert_eq!(error.labels.len(), 2);
+		assert_eq!(error.labels[0].0, LabelStyle::Secondary);
+		assert_eq!(error.labels[1].0, LabelStyle::Primary);
+	}
+
+	#[test]
+	fn test_check_range_and_slice_expr()
+	{
+		let uint8 = DataType::Known(KnownDataType::UInt8);
+		assert_eq!(
+			check_expression("0..3u8").get_data_type(),
+			&DataType::Range(Box::new(uint8.clone()))
+		);
+		assert_eq!(
+			check_expression("[1u8, 2, 3][0..2]").get_data_type(),
+			&DataType::Slice(Box::new(uint8))
+		);
+
+		// constant bounds inside of the list are accepted
+		for source_code in &[
+			"[1, 2, 3][0..3]",
+			"[1, 2, 3][1..3][0..2]",
+			"[1, 2, 3][0..if false then 9 else 1]",
+			"[1, 2, 3][(1..1)]",
+			"{ [1, 2, 3] }[0..3]",
+		]
+		{
+			assert!(
+				check(source_code).is_ok(),
+				"{}",
+				source_code
+			);
+		}
+	}
+
+	#[test]
+	fn test_check_range_and_slice_expr_errors()
+	{
+		assert_eq!(
+			check_error_code("0..true"),
+			SemanticCheckerErrorCode::InvalidRange
+		);
+		assert_eq!(
+			check_error_code("1u8[0..1]"),
+			SemanticCheckerErrorCode::InvalidSlice
+		);
+		assert_eq!(
+			check_error_code("[1][1u8]"),
+			SemanticCheckerErrorCode::InvalidSlice
+		);
+
+		for source_code in &[
+			"[1, 2, 3][-1..2]",
+			"[1, 2, 3][2..1]",
+			"[1, 2, 3][0..4]",
+			"[1, 2, 3][0..2][1..3]",
+			"[1, 2, 3][0..if true then 9 else 1]",
+			"[1, 2, 3][3u8..1u8]",
+			"{ [1, 2, 3] }[(0..4)]",
+			"[1, 2, 3][-1i8..2i8]",
+			"[1, 2, 3][-(1i8)..2i8]",
+			"[1, 2, 3][0..2u8 + 5u8]",
+			"[1, 2, 3][0u8..(2u8 * 3u8 - 1u8)]",
+			"[1, 2, 3][0u128..340282366920938463463374607431768211455u128]",
+			"[1, 2, 3][0n..100000000000000000000000000000000000000000n]",
+			"[1, 2, 3][-100000000000000000000000000000000000000000n..0n]",
+		]
+		{
+			assert_eq!(
+				check_error_code(source_code),
+				SemanticCheckerErrorCode::SliceOutOfBounds,
+				"{}",
+				source_code
+			);
+		}
+	}
+
+	#[test]
+	fn test_check_slice_bounds_overflow_policy()
+	{
+		let is_out_of_bounds =
+			|source_code: &str, overflow_policy| {
+				match check_with_config(
+					source_code,
+					CompilerModeConfig {
+						overflow_policy,
+						..Default::default()
+					},
+				)
+				{
+					Ok(_) => false,
+					Err(error) =>
+					{
+						error.code
+							== SemanticCheckerErrorCode::SliceOutOfBounds
+					}
+				}
+			};
+
+		// 255u8 + 3u8 is 2 when it wraps, 255 when it
+		// saturates and traps when it is checked
+		let source_code = "[1, 2, 3][0u8..255u8 + 3u8]";
+		assert!(!is_out_of_bounds(
+			source_code,
+			OverflowPolicy::Checked
+		));
+		assert!(!is_out_of_bounds(
+			source_code,
+			OverflowPolicy::Wrapping
+		));
+		assert!(is_out_of_bounds(
+			source_code,
+			OverflowPolicy::Saturating
+		));
+
+		// -(-128i8) is -128 when it wraps
+		let source_code = "[1, 2, 3][-(-128i8)..0i8]";
+		assert!(is_out_of_bounds(
+			source_code,
+			OverflowPolicy::Wrapping
+		));
+		assert!(!is_out_of_bounds(
+			source_code,
+			OverflowPolicy::Checked
+		));
+	}
+
+	#[test]
+	fn test_check_defer_stmt()
+	{
+		// a block is its own scope, so it can defer statements
+		// even if the block is deferred
+		for source_code in &[
+			"defer echo 1",
+			"{ defer echo 1; 2 }",
+			"defer { defer echo 1; 2 }",
+		]
+		{
+			assert!(
+				check(source_code).is_ok(),
+				"{}",
+				source_code
+			);
+		}
+
+		for source_code in
+			&["defer defer echo 1", "defer @inline\ndefer echo 1"]
+		{
+			assert_eq!(
+				check_error_code(source_code),
+				SemanticCheckerErrorCode::NestedDefer,
+				"{}",
+				source_code
+			);
+		}
+	}
+
+	#[test]
+	fn test_check_assert_stmt()
+	{
+		let is_enabled = |debug_assertions: bool| {
+			let (module, _) = check_with_config(
+				"assert if true then false else true",
+				CompilerModeConfig {
+					debug_assertions,
+					..Default::default()
+				},
+			)
+			.unwrap();
+			match &module.statements[0]
+			{
+				AnnotatedStatement::Assert(assert_stmt) =>
+				{
+					assert_stmt.is_enabled
+				}
+				statement => panic!(
+					"expected an assert statement, found {:?}",
+					statement
+				),
+			}
+		};
+		assert!(is_enabled(true));
+		assert!(!is_enabled(false));
+
+		for source_code in &[
+			"static_assert true",
+			"static_assert (if true then true else false)",
+			"static_assert { if false then false else true }",
+		]
+		{
+			assert!(
+				check(source_code).is_ok(),
+				"{}",
+				source_code
+			);
+		}
+	}
+
+	#[test]
+	fn test_check_assert_stmt_errors()
+	{
+		assert_eq!(
+			check_error_code("assert 1"),
+			SemanticCheckerErrorCode::InvalidCondition
+		);
+		assert_eq!(
+			check_error_code("static_assert 1u8"),
+			SemanticCheckerErrorCode::InvalidCondition
+		);
+		assert_eq!(
+			check_error_code("static_assert false"),
+			SemanticCheckerErrorCode::StaticAssertFailed
+		);
+		assert_eq!(
+			check_error_code(
+				"static_assert if true then false else true"
+			),
+			SemanticCheckerErrorCode::StaticAssertFailed
+		);
+		assert_eq!(
+			check_error_code(
+				"static_assert { assert true; true }"
+			),
+			SemanticCheckerErrorCode::NonConstantStaticAssert
+		);
+	}
+
+	#[test]
+	fn test_check_attributed_stmt()
+	{
+		// the main function is the only function, so a valid
+		// attribute is checked against it directly
+		let tokens = Lexer::tokenize(
+			"string".into(),
+			"@test\n@deprecated(1)\necho 1".to_owned(),
+		)
+		.unwrap();
+		let module =
+			Parser::parse("string".into(), true, tokens).unwrap();
+		let config = Config::new(
+			GlobalConfig::default(),
+			InnerConfig::Compiler(Default::default()),
+		);
+		let checker = SemanticChecker::new(
+			"string".into(),
+			module.clone(),
+			&config,
+		);
+		let main_function = &*module.statements[0];
+		let ast::Statement::FunctionDeclaration(function) =
+			main_function
+		else
+		{
+			panic!("expected the main function");
+		};
+		let ast::Statement::Attributed(attributed_stmt) =
+			&*function.body[0]
+		else
+		{
+			panic!("expected an attributed statement");
+		};
+		for attribute in &attributed_stmt.attributes
+		{
+			assert!(
+				checker
+					.check_attribute(attribute, main_function)
+					.is_ok()
+			);
+		}
+
+		for (source_code, code) in [
+			(
+				"@inline echo 1",
+				SemanticCheckerErrorCode::InvalidAttributeTarget,
+			),
+			(
+				"@test\n@deprecated(1)\necho 1",
+				SemanticCheckerErrorCode::InvalidAttributeTarget,
+			),
+			(
+				"@inline(1) echo 1",
+				SemanticCheckerErrorCode::InvalidAttributeArguments,
+			),
+			(
+				"@deprecated(1, 2) echo 1",
+				SemanticCheckerErrorCode::InvalidAttributeArguments,
+			),
+			(
+				"@foo echo 1",
+				SemanticCheckerErrorCode::UnknownAttribute,
+			),
+		]
+		{
+			assert_eq!(
+				check_error_code(source_code),
+				code,
+				"{}",
+				source_code
+			);
+		}
+
+		let error = check("@inlin echo 1").unwrap_err();
+		assert_eq!(
+			error.code,
+			SemanticCheckerErrorCode::UnknownAttribute
+		);
+		assert!(error.hint.unwrap().contains("inline"));
+	}
 }
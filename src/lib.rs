@@ -1,4 +1,4 @@
-#![feature(io_error_more, let_chains)]
+#![feature(f16, io_error_more, let_chains)]
 #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
 
 pub mod clap_utils;
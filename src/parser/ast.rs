@@ -1,3 +1,4 @@
+use num_bigint::BigInt;
 use smol_str::SmolStr;
 
 use super::span::Span;
@@ -36,6 +37,29 @@ pub trait AstVisitor<
 		&mut self,
 		echo_stmt: &EchoStmt<SourceExprType>,
 	) -> StmtRetType;
+	/// Visit an assert statement node
+	fn visit_assert_stmt(
+		&mut self,
+		assert_stmt: &AssertStmt<SourceExprType>,
+	) -> StmtRetType;
+	/// Visit a static assert statement node
+	fn visit_static_assert_stmt(
+		&mut self,
+		static_assert_stmt: &AssertStmt<SourceExprType>,
+	) -> StmtRetType;
+	/// Visit an attributed statement node
+	fn visit_attributed_stmt(
+		&mut self,
+		attributed_stmt: &AttributedStmt<
+			SourceExprType,
+			SourceStmtType,
+		>,
+	) -> StmtRetType;
+	/// Visit a defer statement node
+	fn visit_defer_stmt(
+		&mut self,
+		defer_stmt: &DeferStmt<SourceStmtType>,
+	) -> StmtRetType;
 	/// Visit a function declaration statement node
 	fn visit_function_decl_stmt(
 		&mut self,
@@ -66,6 +90,36 @@ pub trait AstVisitor<
 		&mut self,
 		binary_expr: &BinaryExpr<SourceExprType>,
 	) -> ExprRetType;
+	/// Visit a block expression node
+	fn visit_block_expr(
+		&mut self,
+		block_expr: &BlockExpr<SourceExprType, SourceStmtType>,
+	) -> ExprRetType;
+	/// Visit an if expression node
+	fn visit_if_expr(
+		&mut self,
+		if_expr: &IfExpr<SourceExprType>,
+	) -> ExprRetType;
+	/// Visit a list expression node
+	fn visit_list_expr(
+		&mut self,
+		list_expr: &ListExpr<SourceExprType>,
+	) -> ExprRetType;
+	/// Visit a map expression node
+	fn visit_map_expr(
+		&mut self,
+		map_expr: &MapExpr<SourceExprType>,
+	) -> ExprRetType;
+	/// Visit a range expression node
+	fn visit_range_expr(
+		&mut self,
+		range_expr: &RangeExpr<SourceExprType>,
+	) -> ExprRetType;
+	/// Visit a slice expression node
+	fn visit_slice_expr(
+		&mut self,
+		slice_expr: &SliceExpr<SourceExprType>,
+	) -> ExprRetType;
 }
 
 /// Possible values for the AST
@@ -92,6 +146,12 @@ pub enum Value
 	Int32(i32),
 	/// Signed 64-bit integer
 	Int64(i64),
+	/// Unsigned 128-bit integer
+	UInt128(u128),
+	/// Signed 128-bit integer
+	Int128(i128),
+	/// Arbitrary-precision signed integer
+	BigInt(BigInt),
 	/// Platform-dependent integer
 	// initially, it will be stored as 64bit but later in
 	// codegen, it will be converted to the
@@ -102,10 +162,20 @@ pub enum Value
 	// codegen, it will be converted to the
 	// platform-dependent unsigned integer
 	UInt(u64),
+	/// 16-bit floating point
+	Float16(f16),
 	/// 32-bit floating point
 	Float32(f32),
 	/// 64-bit floating point
 	Double(f64),
+	/// Boolean
+	Bool(bool),
+	/// Integer literal without suffix
+	// its data type is decided by the context it is used in
+	UntypedInt(u128),
+	/// Float literal without suffix
+	// its data type is decided by the context it is used in
+	UntypedFloat(f64),
 }
 
 /// Trait for getting the span of a node
@@ -306,6 +376,270 @@ where
 	}
 }
 
+/// Block expression node
+/// The value of the block is the value of its trailing
+/// expression.
+#[derive(Debug, Clone)]
+pub struct BlockExpr<E, S>
+where
+	E: GetSpan,
+	S: GetSpan,
+{
+	/// Left brace
+	pub left_brace_token: Option<Token>,
+	/// Statements inside the block
+	pub statements: Vec<Box<S>>,
+	/// Trailing expression of the block
+	pub expression: Box<E>,
+	/// Right brace
+	pub right_brace_token: Option<Token>,
+}
+
+/// `GetSpan` implementation for `BlockExpr`
+impl<E, S> GetSpan for BlockExpr<E, S>
+where
+	E: GetSpan,
+	S: GetSpan,
+{
+	fn get_span(&self) -> Option<Span>
+	{
+		let start = self
+			.left_brace_token
+			.as_ref()
+			.map(|token| token.span.start);
+		let end = self
+			.right_brace_token
+			.as_ref()
+			.map(|token| token.span.end);
+
+		ternary!(
+			start.is_some() && end.is_some(),
+			Some(Span {
+				start: start.unwrap(),
+				end: end.unwrap()
+			}),
+			None
+		)
+	}
+}
+
+/// If expression node
+#[derive(Debug, Clone)]
+pub struct IfExpr<E>
+where
+	E: GetSpan,
+{
+	/// If keyword
+	pub if_token: Option<Token>,
+	/// Condition expression
+	pub condition: Box<E>,
+	/// Then keyword
+	pub then_token: Option<Token>,
+	/// Expression evaluated when the condition is true
+	pub then_branch: Box<E>,
+	/// Else keyword
+	pub else_token: Option<Token>,
+	/// Expression evaluated when the condition is false
+	pub else_branch: Box<E>,
+}
+
+/// `GetSpan` implementation for `IfExpr`
+impl<E> GetSpan for IfExpr<E>
+where
+	E: GetSpan,
+{
+	fn get_span(&self) -> Option<Span>
+	{
+		if let (Some(if_token), Some(else_span)) =
+			(&self.if_token, self.else_branch.get_span())
+		{
+			Some(Span {
+				start: if_token.span.start,
+				end: else_span.end,
+			})
+		}
+		else
+		{
+			None
+		}
+	}
+}
+
+/// List expression node
+/// The element type of the list is inferred from its
+/// elements.
+#[derive(Debug, Clone)]
+pub struct ListExpr<E>
+where
+	E: GetSpan,
+{
+	/// Left bracket
+	pub left_bracket_token: Option<Token>,
+	/// Elements of the list
+	pub elements: Vec<E>,
+	/// Right bracket
+	pub right_bracket_token: Option<Token>,
+}
+
+/// `GetSpan` implementation for `ListExpr`
+impl<E> GetSpan for ListExpr<E>
+where
+	E: GetSpan,
+{
+	fn get_span(&self) -> Option<Span>
+	{
+		let start = self
+			.left_bracket_token
+			.as_ref()
+			.map(|token| token.span.start);
+		let end = self
+			.right_bracket_token
+			.as_ref()
+			.map(|token| token.span.end);
+
+		ternary!(
+			start.is_some() && end.is_some(),
+			Some(Span {
+				start: start.unwrap(),
+				end: end.unwrap()
+			}),
+			None
+		)
+	}
+}
+
+/// Entry of a map expression
+#[derive(Debug, Clone)]
+pub struct MapEntry<E>
+where
+	E: GetSpan,
+{
+	/// Key expression
+	pub key: E,
+	/// Colon between the key and the value
+	pub colon_token: Option<Token>,
+	/// Value expression
+	pub value: E,
+}
+
+/// Map expression node
+/// The key and value types of the map are inferred from
+/// its entries.
+#[derive(Debug, Clone)]
+pub struct MapExpr<E>
+where
+	E: GetSpan,
+{
+	/// Left brace
+	pub left_brace_token: Option<Token>,
+	/// Entries of the map in insertion order
+	pub entries: Vec<MapEntry<E>>,
+	/// Right brace
+	pub right_brace_token: Option<Token>,
+}
+
+/// `GetSpan` implementation for `MapExpr`
+impl<E> GetSpan for MapExpr<E>
+where
+	E: GetSpan,
+{
+	fn get_span(&self) -> Option<Span>
+	{
+		let start = self
+			.left_brace_token
+			.as_ref()
+			.map(|token| token.span.start);
+		let end = self
+			.right_brace_token
+			.as_ref()
+			.map(|token| token.span.end);
+
+		ternary!(
+			start.is_some() && end.is_some(),
+			Some(Span {
+				start: start.unwrap(),
+				end: end.unwrap()
+			}),
+			None
+		)
+	}
+}
+
+/// Range expression node
+/// The range includes its start and excludes its end.
+#[derive(Debug, Clone)]
+pub struct RangeExpr<E>
+where
+	E: GetSpan,
+{
+	/// Start of the range
+	pub start: Box<E>,
+	/// Range operator token
+	pub operator_token: Option<Token>,
+	/// End of the range
+	pub end: Box<E>,
+}
+
+/// `GetSpan` implementation for `RangeExpr`
+impl<E> GetSpan for RangeExpr<E>
+where
+	E: GetSpan,
+{
+	fn get_span(&self) -> Option<Span>
+	{
+		match (self.start.get_span(), self.end.get_span())
+		{
+			(Some(start_span), Some(end_span)) => Some(Span {
+				start: start_span.start,
+				end: end_span.end,
+			}),
+			_ => None,
+		}
+	}
+}
+
+/// Slice expression node
+/// A slice is a view of a part of a list.
+#[derive(Debug, Clone)]
+pub struct SliceExpr<E>
+where
+	E: GetSpan,
+{
+	/// Expression that is sliced
+	pub target: Box<E>,
+	/// Left bracket
+	pub left_bracket_token: Option<Token>,
+	/// Range of the slice
+	pub range: Box<E>,
+	/// Right bracket
+	pub right_bracket_token: Option<Token>,
+}
+
+/// `GetSpan` implementation for `SliceExpr`
+impl<E> GetSpan for SliceExpr<E>
+where
+	E: GetSpan,
+{
+	fn get_span(&self) -> Option<Span>
+	{
+		let start =
+			self.target.get_span().map(|span| span.start);
+		let end = self
+			.right_bracket_token
+			.as_ref()
+			.map(|token| token.span.end);
+
+		ternary!(
+			start.is_some() && end.is_some(),
+			Some(Span {
+				start: start.unwrap(),
+				end: end.unwrap()
+			}),
+			None
+		)
+	}
+}
+
 /// Possible expression nodes
 #[derive(Debug, Clone)]
 pub enum Expression
@@ -318,6 +652,18 @@ pub enum Expression
 	Unary(UnaryExpr<Expression>),
 	/// Binary expression
 	Binary(BinaryExpr<Expression>),
+	/// Block expression
+	Block(BlockExpr<Expression, Statement<Expression>>),
+	/// If expression
+	If(IfExpr<Expression>),
+	/// List expression
+	List(ListExpr<Expression>),
+	/// Map expression
+	Map(MapExpr<Expression>),
+	/// Range expression
+	Range(RangeExpr<Expression>),
+	/// Slice expression
+	Slice(SliceExpr<Expression>),
 }
 
 /// `GetSpan` implementation for `Expression`
@@ -331,6 +677,12 @@ impl GetSpan for Expression
 			Expression::Grouping(grouping) => grouping.get_span(),
 			Expression::Unary(unary) => unary.get_span(),
 			Expression::Binary(binary) => binary.get_span(),
+			Expression::Block(block) => block.get_span(),
+			Expression::If(if_expr) => if_expr.get_span(),
+			Expression::List(list) => list.get_span(),
+			Expression::Map(map) => map.get_span(),
+			Expression::Range(range) => range.get_span(),
+			Expression::Slice(slice) => slice.get_span(),
 		}
 	}
 }
@@ -391,6 +743,171 @@ where
 	}
 }
 
+/// Assert statement node
+/// Used by both `assert` and `static_assert` statements.
+#[derive(Debug, Clone)]
+pub struct AssertStmt<E>
+where
+	E: GetSpan,
+{
+	/// Assert or static assert keyword
+	pub assert_token: Option<Token>,
+	/// Asserted condition
+	pub condition: E,
+}
+
+/// `GetSpan` implementation for `AssertStmt`
+impl<E> GetSpan for AssertStmt<E>
+where
+	E: GetSpan,
+{
+	fn get_span(&self) -> Option<Span>
+	{
+		match (&self.assert_token, self.condition.get_span())
+		{
+			(Some(assert_token), Some(condition_span)) =>
+			{
+				Some(Span {
+					start: assert_token.span.start,
+					end: condition_span.end,
+				})
+			}
+			_ => None,
+		}
+	}
+}
+
+/// Attribute node
+/// An attribute such as `@inline` or `@deprecated(...)`
+/// annotates the statement after it.
+#[derive(Debug, Clone)]
+pub struct Attribute<E>
+where
+	E: GetSpan,
+{
+	/// Attribute name with its `@` prefix
+	pub attribute_token: Option<Token>,
+	/// Left parenthesis of the arguments
+	pub left_paren_token: Option<Token>,
+	/// Arguments of the attribute
+	pub arguments: Vec<E>,
+	/// Right parenthesis of the arguments
+	pub right_paren_token: Option<Token>,
+}
+
+/// Implementation of `Attribute`
+impl<E> Attribute<E>
+where
+	E: GetSpan,
+{
+	/// Get the name of the attribute without the `@` prefix
+	pub fn name(&self) -> Option<&str>
+	{
+		self
+			.attribute_token
+			.as_ref()
+			.map(|token| token.lexeme.trim_start_matches('@'))
+	}
+}
+
+/// `GetSpan` implementation for `Attribute`
+impl<E> GetSpan for Attribute<E>
+where
+	E: GetSpan,
+{
+	fn get_span(&self) -> Option<Span>
+	{
+		let attribute_span = self
+			.attribute_token
+			.as_ref()
+			.map(|token| token.span)?;
+		let end = self
+			.right_paren_token
+			.as_ref()
+			.map_or(attribute_span.end, |token| token.span.end);
+
+		Some(Span {
+			start: attribute_span.start,
+			end,
+		})
+	}
+}
+
+/// Attributed statement node
+#[derive(Debug, Clone)]
+pub struct AttributedStmt<E, S>
+where
+	E: GetSpan,
+	S: GetSpan,
+{
+	/// Attributes of the statement
+	pub attributes: Vec<Attribute<E>>,
+	/// Attributed statement
+	pub statement: Box<S>,
+}
+
+/// `GetSpan` implementation for `AttributedStmt`
+impl<E, S> GetSpan for AttributedStmt<E, S>
+where
+	E: GetSpan,
+	S: GetSpan,
+{
+	fn get_span(&self) -> Option<Span>
+	{
+		let start = self
+			.attributes
+			.first()
+			.and_then(|attribute| attribute.get_span())
+			.map(|span| span.start);
+		let end =
+			self.statement.get_span().map(|span| span.end);
+
+		ternary!(
+			start.is_some() && end.is_some(),
+			Some(Span {
+				start: start.unwrap(),
+				end: end.unwrap()
+			}),
+			None
+		)
+	}
+}
+
+/// Defer statement node
+/// The deferred statement runs when the enclosing block
+/// exits, after the statements deferred later in the block.
+#[derive(Debug, Clone)]
+pub struct DeferStmt<S>
+where
+	S: GetSpan,
+{
+	/// Defer keyword
+	pub defer_token: Option<Token>,
+	/// Deferred statement
+	pub statement: Box<S>,
+}
+
+/// `GetSpan` implementation for `DeferStmt`
+impl<S> GetSpan for DeferStmt<S>
+where
+	S: GetSpan,
+{
+	fn get_span(&self) -> Option<Span>
+	{
+		match (&self.defer_token, self.statement.get_span())
+		{
+			(Some(defer_token), Some(statement_span)) =>
+			{
+				Some(Span {
+					start: defer_token.span.start,
+					end: statement_span.end,
+				})
+			}
+			_ => None,
+		}
+	}
+}
+
 /// Function parameter node
 #[derive(Debug, Clone)]
 pub struct FunctionDeclParameter
@@ -458,6 +975,14 @@ where
 	Expression(ExpressionStmt<E>),
 	/// Echo statement
 	Echo(EchoStmt<E>),
+	/// Assert statement
+	Assert(AssertStmt<E>),
+	/// Static assert statement
+	StaticAssert(AssertStmt<E>),
+	/// Attributed statement
+	Attributed(AttributedStmt<E, Statement<E>>),
+	/// Defer statement
+	Defer(DeferStmt<Statement<E>>),
 	/// Function declaration statement
 	FunctionDeclaration(FunctionDeclStmt<Statement<E>>),
 }
@@ -473,6 +998,13 @@ where
 		{
 			Statement::Expression(expr) => expr.get_span(),
 			Statement::Echo(echo) => echo.get_span(),
+			Statement::Assert(assert) => assert.get_span(),
+			Statement::StaticAssert(assert) => assert.get_span(),
+			Statement::Attributed(attributed) =>
+			{
+				attributed.get_span()
+			}
+			Statement::Defer(defer) => defer.get_span(),
 			Statement::FunctionDeclaration(func) =>
 			{
 				func.get_span()
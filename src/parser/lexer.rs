@@ -29,6 +29,18 @@ pub enum LexerErrorCode
 	UnexpectedCharacter,
 	/// Invalid number width
 	InvalidNumberLiteralWidth,
+	/// Digit separator before the first digit like `0x_ff`
+	LeadingDigitSeparator,
+	/// Digit separator after the last digit like `1_`
+	TrailingDigitSeparator,
+	/// More than one digit separator in a row like `1__0`
+	ConsecutiveDigitSeparators,
+	/// Exponent without digits like `1e`
+	MissingExponentDigits,
+	/// Hexadecimal float without `p` exponent like `0x1.8`
+	MissingHexFloatExponent,
+	/// Float with bigint suffix like `1.5n`
+	FloatBigIntLiteral,
 	/// Unimplemented feature
 	UnimplementedFeature,
 }
@@ -377,10 +389,28 @@ impl Lexer
 				}
 			}
 			"%" => Ok(self.new_token(TokenKind::Modulo)),
+			"." if self.match_and_consume(".") =>
+			{
+				Ok(self.new_token(TokenKind::Range))
+			}
 			"(" => Ok(self.new_token(TokenKind::LeftParen)),
 			")" => Ok(self.new_token(TokenKind::RightParen)),
+			"{" => Ok(self.new_token(TokenKind::LeftBrace)),
+			"}" => Ok(self.new_token(TokenKind::RightBrace)),
+			"[" => Ok(self.new_token(TokenKind::LeftBracket)),
+			"]" => Ok(self.new_token(TokenKind::RightBracket)),
 			";" => Ok(self.new_token(TokenKind::SemiColon)),
 			":" => Ok(self.new_token(TokenKind::Colon)),
+			"," => Ok(self.new_token(TokenKind::Comma)),
+			"@"
+				if self.peek().map_or(false, |c| {
+					c.chars().all(|rust_char| {
+						UnicodeXID::is_xid_start(rust_char)
+					})
+				}) =>
+			{
+				self.create_attribute_token()
+			}
 			" " =>
 			{
 				while !self.is_eoi()
@@ -584,6 +614,29 @@ impl Lexer
 		Ok(self.new_token(TokenKind::SingleLineComment))
 	}
 
+	/// Create an attribute token.
+	/// The `@` is already consumed and the name starts at the
+	/// current character.
+	///
+	/// # Returns
+	///
+	/// The attribute token.
+	fn create_attribute_token(
+		&mut self,
+	) -> LexerResult<Option<Token>>
+	{
+		while !self.is_eoi()
+			&& self.peek().map_or(false, |c| {
+				c.chars().all(|rust_char| {
+					UnicodeXID::is_xid_continue(rust_char)
+				})
+			})
+		{
+			self.advance();
+		}
+		Ok(self.new_token(TokenKind::Attribute))
+	}
+
 	/// Create an identifier token.
 	///
 	/// # Returns
@@ -607,6 +660,38 @@ impl Lexer
 			[self.start.offset .. self.current.offset]
 		{
 			"echo" => Ok(self.new_token(TokenKind::Echo)),
+			"if" => Ok(self.new_token(TokenKind::If)),
+			"then" => Ok(self.new_token(TokenKind::Then)),
+			"else" => Ok(self.new_token(TokenKind::Else)),
+			"defer" => Ok(self.new_token(TokenKind::Defer)),
+			"assert" => Ok(self.new_token(TokenKind::Assert)),
+			"static_assert" =>
+			{
+				Ok(self.new_token(TokenKind::StaticAssert))
+			}
+			"true" => Ok(self.new_token(TokenKind::Literal {
+				kind: LiteralTokenKind::Boolean(true),
+				suffix_start: None,
+			})),
+			"false" => Ok(self.new_token(TokenKind::Literal {
+				kind: LiteralTokenKind::Boolean(false),
+				suffix_start: None,
+			})),
+			// IEEE special values are spelled as words but are
+			// untyped float constants like `1.5`
+			"inf" | "nan" =>
+			{
+				Ok(self.new_token(TokenKind::Literal {
+					kind: LiteralTokenKind::Float(
+						FloatLiteralToken {
+							base: NumberBase::Decimal,
+							has_exponent_part: false,
+							has_digit_separator: false,
+						},
+					),
+					suffix_start: None,
+				}))
+			}
 			_ => Err(LexerError {
 				code: LexerErrorCode::UnimplementedFeature,
 				message: t!(
@@ -677,8 +762,9 @@ impl Lexer
 		suffix: String,
 	) -> (bool, Vec<&'static str>, Vec<&'static str>)
 	{
-		let int_possible_widths = vec!["8", "16", "32", "64"];
-		let float_possible_widths = vec!["32", "64"];
+		let int_possible_widths =
+			vec!["8", "16", "32", "64", "128"];
+		let float_possible_widths = vec!["16", "32", "64"];
 
 		let res = match suffix.as_str()
 		{
@@ -688,7 +774,7 @@ impl Lexer
 					|| float_possible_widths
 						.contains(&width_lexeme.as_str())
 			}
-			"d" => width_lexeme.is_empty(),
+			"d" | "n" => width_lexeme.is_empty(),
 			"i" | "u" =>
 			{
 				width_lexeme.is_empty()
@@ -704,10 +790,157 @@ impl Lexer
 		(res, int_possible_widths, float_possible_widths)
 	}
 
+	/// Consume a group of digits in the given base.
+	/// Digits can be separated with `_` for readability like
+	/// `1_000_000`, but a separator must always sit between
+	/// two digits.
+	///
+	/// # Arguments
+	///
+	/// * `base` - The base of the digits.
+	/// * `follows_digit` - Whether the group continues a
+	///   digit that is already consumed.
+	///
+	/// # Returns
+	///
+	/// A tuple of booleans, whether any digit is consumed and
+	/// whether any separator is consumed, or a LexerError.
+	fn consume_digits(
+		&mut self,
+		base: NumberBase,
+		follows_digit: bool,
+	) -> LexerResult<(bool, bool)>
+	{
+		let mut has_digit = false;
+		let mut has_separator = false;
+
+		loop
+		{
+			match self.peek()
+			{
+				Some(c) if is_digit(c.clone(), base) =>
+				{
+					has_digit = true;
+					self.advance();
+				}
+				Some(c) if c == "_" =>
+				{
+					has_separator = true;
+					let separator_start = self.current;
+					// consume the separator
+					self.advance();
+
+					if !has_digit && !follows_digit
+					{
+						return Err(self.create_digit_separator_error(
+							LexerErrorCode::LeadingDigitSeparator,
+							"lexer-error-leading-digit-separator",
+							separator_start,
+						));
+					}
+
+					if self.peek().map_or(false, |c| c == "_")
+					{
+						// report the whole run of separators
+						while self.peek().map_or(false, |c| c == "_")
+						{
+							self.advance();
+						}
+						return Err(self.create_digit_separator_error(
+							LexerErrorCode::ConsecutiveDigitSeparators,
+							"lexer-error-consecutive-digit-separators",
+							separator_start,
+						));
+					}
+
+					if !self
+						.peek()
+						.map_or(false, |c| is_digit(c, base))
+					{
+						return Err(self.create_digit_separator_error(
+							LexerErrorCode::TrailingDigitSeparator,
+							"lexer-error-trailing-digit-separator",
+							separator_start,
+						));
+					}
+				}
+				_ => break,
+			}
+		}
+
+		Ok((has_digit, has_separator))
+	}
+
+	/// Create a LexerError for a misplaced digit separator.
+	///
+	/// # Arguments
+	///
+	/// * `code` - The error code.
+	/// * `message_key` - The Fluent key of the error message.
+	/// * `start` - The position of the first offending
+	///   separator.
+	///
+	/// # Returns
+	///
+	/// The LexerError spanning up to the current position.
+	fn create_digit_separator_error(
+		&self,
+		code: LexerErrorCode,
+		message_key: &str,
+		start: Position,
+	) -> LexerError
+	{
+		LexerError {
+			code,
+			message: t!(message_key),
+			hint: Some(t!(format!("{message_key}.hint"))),
+			location: Location::Span(Span::new(
+				start,
+				self.current,
+			)),
+			source_id: self.source_id.clone().into(),
+		}
+	}
+
+	/// Consume the exponent part of a float literal.
+	/// The exponent marker, `e` or `p`, must already be
+	/// consumed. The exponent itself is always decimal.
+	///
+	/// # Returns
+	///
+	/// Whether any separator is consumed, or a LexerError if
+	/// the exponent has no digits.
+	fn consume_exponent(&mut self) -> LexerResult<bool>
+	{
+		// consume the sign
+		if self.peek().map_or(false, |c| c == "+" || c == "-")
+		{
+			self.advance();
+		}
+
+		let (has_digit, has_separator) =
+			self.consume_digits(NumberBase::Decimal, false)?;
+
+		if !has_digit
+		{
+			return Err(LexerError {
+				code: LexerErrorCode::MissingExponentDigits,
+				message: t!("lexer-error-missing-exponent-digits"),
+				hint: None,
+				location: Location::Position(self.current),
+				source_id: self.source_id.clone().into(),
+			});
+		}
+
+		Ok(has_separator)
+	}
+
 	/// Consume the number part of the source code.
 	/// This is used to tokenize integer and float literals.
 	/// The lexer will consume the integer part, fractional
-	/// part, exponent part,
+	/// part, exponent part, and suffix. Decimal floats use
+	/// `e` for the exponent while hexadecimal floats use
+	/// `p`, a power of two, like `0x1.8p3`.
 	///
 	/// # Arguments
 	///
@@ -721,75 +954,93 @@ impl Lexer
 		base: NumberBase,
 	) -> LexerResult<Option<Token>>
 	{
-		let mut has_int_part = base == NumberBase::Decimal;
-
-		// consume the int part
-		while self.peek().map_or(false, |c| is_digit(c, base))
-		{
-			has_int_part = true;
-			self.advance();
-		}
+		// the first decimal digit is already consumed while the
+		// other bases start after their prefix
+		let (has_digit, mut has_separator) = self
+			.consume_digits(base, base == NumberBase::Decimal)?;
+		let has_int_part =
+			has_digit || base == NumberBase::Decimal;
 
 		let mut is_int = true;
 
-		// consume the fractional part if it's base 10 and has
-		// dot followed by digit
-		if base == NumberBase::Decimal
+		// only decimal and hexadecimal numbers can be floats
+		let can_be_float = matches!(
+			base,
+			NumberBase::Decimal | NumberBase::Hexadecimal
+		);
+		let exponent_markers = ternary!(
+			base == NumberBase::Hexadecimal,
+			["p", "P"],
+			["e", "E"]
+		);
+
+		// consume the fractional part if it has dot followed by
+		// digit or separator
+		if can_be_float
 			&& self.peek().map_or(false, |c| c == ".")
 			&& self
 				.peek_nth(1)
-				.map_or(false, |c| is_digit(c, base))
+				.map_or(false, |c| c == "_" || is_digit(c, base))
 		{
 			is_int = false;
 			// consume the dot
 			self.advance();
 
 			// consume the fractional part
-			while self.peek().map_or(false, |c| is_digit(c, base))
-			{
-				self.advance();
-			}
+			let (_, separator) =
+				self.consume_digits(base, false)?;
+			has_separator |= separator;
 		}
 
-		let mut has_expontent = false;
-		// consume the exponent part if it's base 10 and has e
-		// or E
-		if base == NumberBase::Decimal
-			&& self.peek().map_or(false, |c| c == "e" || c == "E")
+		let mut has_exponent = false;
+		// consume the exponent part if it has an exponent
+		// marker
+		if can_be_float
+			&& self.peek().map_or(false, |c| {
+				exponent_markers.contains(&c.as_str())
+			})
 		{
 			is_int = false;
-			has_expontent = true;
+			has_exponent = true;
 
-			// consume the e or E
+			// consume the exponent marker
 			self.advance();
+			has_separator |= self.consume_exponent()?;
+		}
 
-			// consume the sign
-			if self.peek().map_or(false, |c| c == "+" || c == "-")
-			{
-				self.advance();
-			}
-
-			// consume the exponent part
-			while self.peek().map_or(false, |c| is_digit(c, base))
-			{
-				self.advance();
-			}
+		// hexadecimal floats need the binary exponent,
+		// otherwise the fractional digits would be ambiguous
+		// with suffixes
+		if base == NumberBase::Hexadecimal
+			&& !is_int
+			&& !has_exponent
+		{
+			return Err(LexerError {
+				code: LexerErrorCode::MissingHexFloatExponent,
+				message: t!(
+					"lexer-error-missing-hex-float-exponent"
+				),
+				hint: Some(t!(
+					"lexer-error-missing-hex-float-exponent.hint"
+				)),
+				location: Location::Position(self.current),
+				source_id: self.source_id.clone().into(),
+			});
 		}
 
 		let mut suffix_start_pos: Option<Position> = None;
 		// consume the suffix
 		if self.peek().map_or(false, |c| {
-			matches!(c.as_str(), "f" | "d" | "i" | "u")
+			matches!(c.as_str(), "f" | "d" | "i" | "u" | "n")
 		})
 		{
 			suffix_start_pos = Some(self.current);
 			// consume the suffix
 			let suffix = self.advance();
 
-			// consume the width part like 8, 16, 32, 64
-			// the maximum possible width is 64. But some
-			// languages have 128 bit integers. So, let's keep it
-			// 3.
+			// consume the width part like 8, 16, 32, 64, 128
+			// the maximum possible width is 128. So, let's keep
+			// it 3.
 			let mut width_lexeme = String::with_capacity(3);
 
 			while self
@@ -827,6 +1078,23 @@ impl Lexer
 					});
 				}
 
+				if suffix.as_str() == "n"
+				{
+					return Err(LexerError {
+						code: LexerErrorCode::InvalidNumberLiteralWidth,
+						message: t!(
+							"lexer-error-invalid-number-literal-width.\
+							 no-width-for-bigint"
+						),
+						hint: None,
+						location: Location::Span(Span::new(
+							suffix_start_pos.unwrap(),
+							self.current,
+						)),
+						source_id: self.source_id.clone().into(),
+					});
+				}
+
 				let message = t!(
 					"lexer-error-invalid-number-literal-width",
 					width = json!(width_lexeme).to_string(),
@@ -854,6 +1122,23 @@ impl Lexer
 					source_id: self.source_id.clone().into(),
 				});
 			}
+
+			// bigints can only hold integers
+			if suffix.as_str() == "n" && !is_int
+			{
+				return Err(LexerError {
+					code: LexerErrorCode::FloatBigIntLiteral,
+					message: t!("lexer-error-float-bigint-literal"),
+					hint: Some(t!(
+						"lexer-error-float-bigint-literal.hint"
+					)),
+					location: Location::Span(Span::new(
+						self.start,
+						self.current,
+					)),
+					source_id: self.source_id.clone().into(),
+				});
+			}
 		}
 
 		// The suffix is not part of the decision. Only dot and
@@ -867,6 +1152,7 @@ impl Lexer
 					IntegerLiteralToken {
 						base,
 						has_integer_part: has_int_part,
+						has_digit_separator: has_separator,
 					},
 				),
 				suffix_start: suffix_start_pos,
@@ -877,7 +1163,8 @@ impl Lexer
 			Ok(self.new_token(TokenKind::Literal {
 				kind: LiteralTokenKind::Float(FloatLiteralToken {
 					base,
-					has_exponent_part: has_expontent,
+					has_exponent_part: has_exponent,
+					has_digit_separator: has_separator,
 				}),
 				suffix_start: suffix_start_pos,
 			}))
@@ -1024,10 +1311,78 @@ mod tests
 		test_scan_indivitual_token!("/", TokenKind::Divide);
 		test_scan_indivitual_token!("%", TokenKind::Modulo);
 		test_scan_indivitual_token!("**", TokenKind::Exponent);
+		test_scan_indivitual_token!("..", TokenKind::Range);
 		test_scan_indivitual_token!("(", TokenKind::LeftParen);
 		test_scan_indivitual_token!(")", TokenKind::RightParen);
+		test_scan_indivitual_token!("{", TokenKind::LeftBrace);
+		test_scan_indivitual_token!("}", TokenKind::RightBrace);
+		test_scan_indivitual_token!(
+			"[",
+			TokenKind::LeftBracket
+		);
+		test_scan_indivitual_token!(
+			"]",
+			TokenKind::RightBracket
+		);
+		test_scan_indivitual_token!("if", TokenKind::If);
+		test_scan_indivitual_token!("then", TokenKind::Then);
+		test_scan_indivitual_token!("else", TokenKind::Else);
+		test_scan_indivitual_token!("defer", TokenKind::Defer);
+		test_scan_indivitual_token!(
+			"assert",
+			TokenKind::Assert
+		);
+		test_scan_indivitual_token!(
+			"static_assert",
+			TokenKind::StaticAssert
+		);
+		test_scan_indivitual_token!(
+			"@inline",
+			TokenKind::Attribute
+		);
+		test_scan_indivitual_token!(
+			"true",
+			TokenKind::Literal {
+				kind: super::LiteralTokenKind::Boolean(true),
+				suffix_start: None,
+			}
+		);
+		test_scan_indivitual_token!(
+			"false",
+			TokenKind::Literal {
+				kind: super::LiteralTokenKind::Boolean(false),
+				suffix_start: None,
+			}
+		);
+		test_scan_indivitual_token!(
+			"inf",
+			TokenKind::Literal {
+				kind: super::LiteralTokenKind::Float(
+					super::FloatLiteralToken {
+						base: super::NumberBase::Decimal,
+						has_exponent_part: false,
+						has_digit_separator: false,
+					}
+				),
+				suffix_start: None,
+			}
+		);
+		test_scan_indivitual_token!(
+			"nan",
+			TokenKind::Literal {
+				kind: super::LiteralTokenKind::Float(
+					super::FloatLiteralToken {
+						base: super::NumberBase::Decimal,
+						has_exponent_part: false,
+						has_digit_separator: false,
+					}
+				),
+				suffix_start: None,
+			}
+		);
 		test_scan_indivitual_token!(";", TokenKind::SemiColon);
 		test_scan_indivitual_token!(":", TokenKind::Colon);
+		test_scan_indivitual_token!(",", TokenKind::Comma);
 		test_scan_indivitual_token!("\n", TokenKind::NewLine);
 		test_scan_indivitual_token!(
 			"// hello",
@@ -1084,6 +1439,11 @@ mod tests
 				.validate_number_width("32".into(), "f".into());
 		assert!(valid);
 
+		let (valid, ..) =
+			Lexer::new("string".into(), "".into())
+				.validate_number_width("16".into(), "f".into());
+		assert!(valid);
+
 		let (valid, ..) =
 			Lexer::new("string".into(), "".into())
 				.validate_number_width("128".into(), "f".into());
@@ -1117,6 +1477,11 @@ mod tests
 		let (valid, ..) =
 			Lexer::new("string".into(), "".into())
 				.validate_number_width("128".into(), "i".into());
+		assert!(valid);
+
+		let (valid, ..) =
+			Lexer::new("string".into(), "".into())
+				.validate_number_width("256".into(), "i".into());
 		assert!(!valid);
 
 		let (valid, ..) =
@@ -1132,6 +1497,11 @@ mod tests
 		let (valid, ..) =
 			Lexer::new("string".into(), "".into())
 				.validate_number_width("128".into(), "u".into());
+		assert!(valid);
+
+		let (valid, ..) =
+			Lexer::new("string".into(), "".into())
+				.validate_number_width("256".into(), "u".into());
 		assert!(!valid);
 
 		let (valid, ..) =
@@ -1150,6 +1520,7 @@ mod tests
 					super::IntegerLiteralToken {
 						base: super::NumberBase::Decimal,
 						has_integer_part: true,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: None,
@@ -1163,6 +1534,7 @@ mod tests
 					super::IntegerLiteralToken {
 						base: super::NumberBase::Hexadecimal,
 						has_integer_part: false,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: None,
@@ -1176,6 +1548,7 @@ mod tests
 					super::IntegerLiteralToken {
 						base: super::NumberBase::Binary,
 						has_integer_part: false,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: None,
@@ -1189,6 +1562,7 @@ mod tests
 					super::IntegerLiteralToken {
 						base: super::NumberBase::Octal,
 						has_integer_part: false,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: None,
@@ -1202,6 +1576,7 @@ mod tests
 					super::IntegerLiteralToken {
 						base: super::NumberBase::Hexadecimal,
 						has_integer_part: true,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: None,
@@ -1215,6 +1590,7 @@ mod tests
 					super::IntegerLiteralToken {
 						base: super::NumberBase::Binary,
 						has_integer_part: true,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: None,
@@ -1228,6 +1604,7 @@ mod tests
 					super::IntegerLiteralToken {
 						base: super::NumberBase::Octal,
 						has_integer_part: true,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: None,
@@ -1241,6 +1618,7 @@ mod tests
 					super::FloatLiteralToken {
 						base: super::NumberBase::Decimal,
 						has_exponent_part: false,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: None,
@@ -1254,6 +1632,7 @@ mod tests
 					super::FloatLiteralToken {
 						base: super::NumberBase::Decimal,
 						has_exponent_part: true,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: None,
@@ -1267,6 +1646,7 @@ mod tests
 					super::FloatLiteralToken {
 						base: super::NumberBase::Decimal,
 						has_exponent_part: true,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: None,
@@ -1280,6 +1660,7 @@ mod tests
 					super::FloatLiteralToken {
 						base: super::NumberBase::Decimal,
 						has_exponent_part: false,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: Some(super::Position {
@@ -1298,6 +1679,7 @@ mod tests
 					super::IntegerLiteralToken {
 						base: super::NumberBase::Decimal,
 						has_integer_part: true,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: Some(super::Position {
@@ -1316,6 +1698,7 @@ mod tests
 					super::IntegerLiteralToken {
 						base: super::NumberBase::Decimal,
 						has_integer_part: true,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: Some(super::Position {
@@ -1334,6 +1717,7 @@ mod tests
 					super::IntegerLiteralToken {
 						base: super::NumberBase::Decimal,
 						has_integer_part: true,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: Some(super::Position {
@@ -1352,6 +1736,7 @@ mod tests
 					super::IntegerLiteralToken {
 						base: super::NumberBase::Decimal,
 						has_integer_part: true,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: Some(super::Position {
@@ -1370,6 +1755,7 @@ mod tests
 					super::IntegerLiteralToken {
 						base: super::NumberBase::Decimal,
 						has_integer_part: true,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: Some(super::Position {
@@ -1388,6 +1774,7 @@ mod tests
 					super::IntegerLiteralToken {
 						base: super::NumberBase::Decimal,
 						has_integer_part: true,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: Some(super::Position {
@@ -1404,8 +1791,8 @@ mod tests
 	fn test_number_with_invalid_widths()
 	{
 		for literal in &[
-			"1f8", "1f16", "1d8", "1d16", "1d64", "1i12", "32u1",
-			"12.0f60",
+			"1f8", "1f128", "1d8", "1d16", "1d64", "1i12",
+			"32u1", "1u256", "12.0f60",
 		]
 		{
 			let tokens = Lexer::tokenize(
@@ -1422,6 +1809,235 @@ mod tests
 		}
 	}
 
+	#[test]
+	fn test_number_with_digit_separators()
+	{
+		test_scan_indivitual_token!(
+			"1_000_000u64",
+			TokenKind::Literal {
+				kind: super::LiteralTokenKind::Integer(
+					super::IntegerLiteralToken {
+						base: super::NumberBase::Decimal,
+						has_integer_part: true,
+						has_digit_separator: true,
+					},
+				),
+				suffix_start: Some(super::Position {
+					line: 1,
+					column: 10,
+					offset: 9,
+					char_index: 9,
+				}),
+			}
+		);
+
+		test_scan_indivitual_token!(
+			"0xffff_ffff",
+			TokenKind::Literal {
+				kind: super::LiteralTokenKind::Integer(
+					super::IntegerLiteralToken {
+						base: super::NumberBase::Hexadecimal,
+						has_integer_part: true,
+						has_digit_separator: true,
+					},
+				),
+				suffix_start: None,
+			}
+		);
+
+		test_scan_indivitual_token!(
+			"1_000.000_1e1_0",
+			TokenKind::Literal {
+				kind: super::LiteralTokenKind::Float(
+					super::FloatLiteralToken {
+						base: super::NumberBase::Decimal,
+						has_exponent_part: true,
+						has_digit_separator: true,
+					},
+				),
+				suffix_start: None,
+			}
+		);
+	}
+
+	#[test]
+	fn test_number_with_invalid_digit_separators()
+	{
+		for (literal, code) in &[
+			("0x_ff", super::LexerErrorCode::LeadingDigitSeparator),
+			("1._5", super::LexerErrorCode::LeadingDigitSeparator),
+			("1e_5", super::LexerErrorCode::LeadingDigitSeparator),
+			("1_", super::LexerErrorCode::TrailingDigitSeparator),
+			("1_u8", super::LexerErrorCode::TrailingDigitSeparator),
+			("1_.5", super::LexerErrorCode::TrailingDigitSeparator),
+			("1e5_", super::LexerErrorCode::TrailingDigitSeparator),
+			(
+				"1__0",
+				super::LexerErrorCode::ConsecutiveDigitSeparators,
+			),
+			(
+				"0b1___0",
+				super::LexerErrorCode::ConsecutiveDigitSeparators,
+			),
+		]
+		{
+			let tokens = Lexer::tokenize(
+				"string".into(),
+				literal.to_owned().to_string(),
+			);
+			assert!(tokens.is_err());
+			assert_eq!(tokens.unwrap_err().code, *code);
+		}
+
+		let error =
+			Lexer::tokenize("string".into(), "1__0".into())
+				.unwrap_err();
+		assert_eq!(
+			error.location,
+			super::Location::Span(super::Span {
+				start: super::Position {
+					line: 1,
+					column: 2,
+					offset: 1,
+					char_index: 1,
+				},
+				end: super::Position {
+					line: 1,
+					column: 4,
+					offset: 3,
+					char_index: 3,
+				},
+			})
+		);
+	}
+
+	#[test]
+	fn test_hex_float_tokens()
+	{
+		for literal in &["0x1.8p3", "0x1p-2", "0xA.Bp+1f32", "0x1_0p1_0"]
+		{
+			let tokens = Lexer::tokenize(
+				"string".into(),
+				literal.to_owned().to_string(),
+			);
+			assert!(tokens.is_ok());
+			let tokens = tokens.unwrap();
+			assert_eq!(tokens.len(), 2);
+			assert!(matches!(
+				tokens[0].kind,
+				TokenKind::Literal {
+					kind: super::LiteralTokenKind::Float(
+						super::FloatLiteralToken {
+							base: super::NumberBase::Hexadecimal,
+							has_exponent_part: true,
+							..
+						}
+					),
+					..
+				}
+			));
+		}
+
+		let tokens =
+			Lexer::tokenize("string".into(), "0x1.8".into());
+		assert_eq!(
+			tokens.unwrap_err().code,
+			super::LexerErrorCode::MissingHexFloatExponent
+		);
+
+		let tokens =
+			Lexer::tokenize("string".into(), "1e+".into());
+		assert_eq!(
+			tokens.unwrap_err().code,
+			super::LexerErrorCode::MissingExponentDigits
+		);
+	}
+
+	#[test]
+	fn test_range_tokens()
+	{
+		let tokens =
+			Lexer::tokenize("string".into(), "1..5".into());
+		assert!(tokens.is_ok());
+		let kinds: Vec<TokenKind> = tokens
+			.unwrap()
+			.iter()
+			.map(|token| token.kind)
+			.collect();
+		assert_eq!(kinds.len(), 4);
+		assert!(matches!(
+			kinds[0],
+			TokenKind::Literal {
+				kind: super::LiteralTokenKind::Integer(_),
+				..
+			}
+		));
+		assert_eq!(kinds[1], TokenKind::Range);
+
+		let tokens =
+			Lexer::tokenize("string".into(), "1.5..0x2".into());
+		assert!(tokens.is_ok());
+		let tokens = tokens.unwrap();
+		assert_eq!(tokens[0].lexeme, "1.5");
+		assert_eq!(tokens[1].kind, TokenKind::Range);
+		assert_eq!(tokens[2].lexeme, "0x2");
+
+		let tokens =
+			Lexer::tokenize("string".into(), ".".into());
+		assert_eq!(
+			tokens.unwrap_err().code,
+			super::LexerErrorCode::UnexpectedCharacter
+		);
+	}
+
+	#[test]
+	fn test_bigint_tokens()
+	{
+		for literal in &["15n", "0xffn", "0b1_0n", "0n"]
+		{
+			let tokens = Lexer::tokenize(
+				"string".into(),
+				literal.to_owned().to_string(),
+			);
+			assert!(tokens.is_ok());
+			let tokens = tokens.unwrap();
+			assert_eq!(tokens.len(), 2);
+			assert!(matches!(
+				tokens[0].kind,
+				TokenKind::Literal {
+					kind: super::LiteralTokenKind::Integer(_),
+					suffix_start: Some(_),
+				}
+			));
+		}
+
+		for literal in &["1.5n", "1e3n"]
+		{
+			let tokens = Lexer::tokenize(
+				"string".into(),
+				literal.to_owned().to_string(),
+			);
+			assert_eq!(
+				tokens.unwrap_err().code,
+				super::LexerErrorCode::FloatBigIntLiteral
+			);
+		}
+
+		let tokens =
+			Lexer::tokenize("string".into(), "1n64".into());
+		let error = tokens.unwrap_err();
+		assert_eq!(
+			error.code,
+			super::LexerErrorCode::InvalidNumberLiteralWidth
+		);
+		assert_eq!(
+			error.message,
+			"Bigint literals do not support width \
+			 specification. They grow as wide as their value \
+			 needs."
+		);
+	}
+
 	#[test]
 	fn test_unimplemented_feature()
 	{
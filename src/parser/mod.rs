@@ -4,10 +4,11 @@ use std::ops::Range;
 use ast::Expression;
 use codespan_reporting::diagnostic::{Diagnostic, Label};
 use codespan_reporting::files::SimpleFiles;
+use num_bigint::BigUint;
 use num_derive::FromPrimitive;
-use num_traits::FromPrimitive;
+use num_traits::{FromPrimitive, Num, ToPrimitive, Zero};
 use smol_str::{SmolStr, ToSmolStr};
-use span::{Location, Position};
+use span::{Location, Position, Span};
 use termcolor::WriteColor;
 use token::{
 	FloatLiteralToken,
@@ -33,6 +34,13 @@ pub enum ParserErrorCode
 {
 	/// Expected an expression
 	ExpectedExpression,
+	/// Expected a specific token
+	ExpectedToken,
+	/// Number literal does not fit in its data type
+	LiteralOutOfRange,
+	/// Block without expressions, which is also how an empty
+	/// map would look
+	EmptyBlock,
 }
 
 /// Parser error
@@ -142,10 +150,30 @@ pub struct Parser
 // stmt = simple_stmts
 //
 // simple_stmts = simple_stmt ~ (SEMICOLON ~ simple_stmt)* ~
-// SEMICOLON? simple_stmt = echo_stmt | expression_stmt
+// SEMICOLON? simple_stmt = attributed_stmt | echo_stmt |
+// assert_stmt | static_assert_stmt | defer_stmt |
+// expression_stmt
 //
+// attributed_stmt = (attribute ~ NEWLINE*)+ ~ simple_stmt
+// attribute = ATTRIBUTE ~ (LPAREN ~ (expr ~ (COMMA ~
+// expr)* ~ COMMA?)? ~ RPAREN)?
 // echo_stmt = ECHO ~ expr
+// assert_stmt = ASSERT ~ expr
+// static_assert_stmt = STATIC_ASSERT ~ expr
+// defer_stmt = DEFER ~ simple_stmt
 // expression_stmt = expr
+//
+// block_expr = LBRACE ~ (simple_stmts ~ NEWLINE*)* ~ RBRACE
+// if_expr = IF ~ expr ~ THEN ~ expr ~ ELSE ~ expr
+// list_expr = LBRACKET ~ (expr ~ (COMMA ~ expr)* ~ COMMA?)?
+// ~ RBRACKET
+// map_expr = LBRACE ~ map_entry ~ (COMMA ~ map_entry)* ~
+// COMMA? ~ RBRACE
+// map_entry = expr ~ COLON ~ expr
+// (empty braces `{}` are a block without a value, never an
+// empty map)
+// range_expr = expr ~ RANGE ~ expr
+// slice_expr = expr ~ LBRACKET ~ expr ~ RBRACKET
 #[derive(
 	Debug,
 	Clone,
@@ -159,6 +187,7 @@ pub struct Parser
 enum Precedence
 {
 	None,
+	Range,
 	Term,
 	Factor,
 	Unary,
@@ -319,13 +348,43 @@ impl Parser
 		Ok(stmts)
 	}
 
+	/// Consume line breaks and indentation tokens.
+	/// Inside braces, neither of them is significant.
+	fn consume_line_breaks(&mut self)
+	{
+		while matches!(
+			self.peek().kind,
+			TokenKind::NewLine
+				| TokenKind::Whitespace
+				| TokenKind::Tab
+		)
+		{
+			self.advance();
+		}
+	}
+
 	/// Parses simple statements.
 	fn parse_simple_stmts(
 		&mut self,
 	) -> ParserResult<Vec<Box<ast::Statement<Expression>>>>
 	{
-		let stmt = self.parse_simple_stmt()?;
-		Ok(vec![stmt])
+		let mut stmts = vec![self.parse_simple_stmt()?];
+
+		while self.match_and_consume(TokenKind::SemiColon)
+		{
+			// the trailing semicolon is optional
+			if matches!(
+				self.peek().kind,
+				TokenKind::NewLine
+					| TokenKind::RightBrace
+					| TokenKind::EndOfInput
+			)
+			{
+				break;
+			}
+			stmts.push(self.parse_simple_stmt()?);
+		}
+		Ok(stmts)
 	}
 
 	/// Parses a simple statement.
@@ -333,10 +392,27 @@ impl Parser
 		&mut self,
 	) -> ParserResult<Box<ast::Statement<Expression>>>
 	{
-		if self.match_and_consume(TokenKind::Echo)
+		if self.peek().kind == TokenKind::Attribute
+		{
+			self.parse_attributed_stmt()
+		}
+		else if self.match_and_consume(TokenKind::Echo)
 		{
 			self.parse_echo_stmt()
 		}
+		else if self.match_and_consume(TokenKind::Assert)
+		{
+			self.parse_assert_stmt()
+		}
+		else if self
+			.match_and_consume(TokenKind::StaticAssert)
+		{
+			self.parse_static_assert_stmt()
+		}
+		else if self.match_and_consume(TokenKind::Defer)
+		{
+			self.parse_defer_stmt()
+		}
 		else
 		{
 			self.parse_expression_stmt()
@@ -392,6 +468,42 @@ impl Parser
 		self.tokens[self.current].clone()
 	}
 
+	/// Consume the current token if it is of the given kind.
+	/// Otherwise, return an error.
+	///
+	/// # Arguments
+	///
+	/// * kind - The token kind to consume.
+	/// * expected - The description of the expected token.
+	///
+	/// # Returns
+	///
+	/// The consumed token or an error.
+	fn consume(
+		&mut self,
+		kind: TokenKind,
+		expected: &str,
+	) -> ParserResult<Token>
+	{
+		let token = self.peek();
+		if token.kind == kind
+		{
+			return Ok(self.advance());
+		}
+
+		Err(ParserError {
+			code: ParserErrorCode::ExpectedToken,
+			message: format!(
+				"expected {}, found {}",
+				expected,
+				token.description(1, "lowercase", None, true, true)
+			),
+			hint: None,
+			location: Location::Span(token.span),
+			source_id: self.source_id.clone(),
+		})
+	}
+
 	/// Parses an echo statement.
 	fn parse_echo_stmt(
 		&mut self,
@@ -406,6 +518,121 @@ impl Parser
 		})))
 	}
 
+	/// Parses a statement with its attributes.
+	/// Attributes can be on the lines before the statement.
+	fn parse_attributed_stmt(
+		&mut self,
+	) -> ParserResult<Box<ast::Statement<Expression>>>
+	{
+		let mut attributes = vec![];
+		while self.match_and_consume(TokenKind::Attribute)
+		{
+			attributes.push(self.parse_attribute()?);
+			self.consume_line_breaks();
+		}
+		let statement = self.parse_simple_stmt()?;
+
+		Ok(Box::new(ast::Statement::Attributed(
+			ast::AttributedStmt {
+				attributes,
+				statement,
+			},
+		)))
+	}
+
+	/// Parses an attribute.
+	/// The arguments must start right after the name, so
+	/// `@name (1)` is an attribute followed by a grouping
+	/// expression.
+	fn parse_attribute(
+		&mut self,
+	) -> ParserResult<ast::Attribute<Expression>>
+	{
+		let attribute_token = self.previous();
+		let next_token = self.peek();
+		if next_token.kind != TokenKind::LeftParen
+			|| next_token.span.start.offset
+				!= attribute_token.span.end.offset
+		{
+			return Ok(ast::Attribute {
+				attribute_token: Some(attribute_token),
+				left_paren_token: None,
+				arguments: vec![],
+				right_paren_token: None,
+			});
+		}
+
+		let left_paren_token = self.advance();
+		let mut arguments = vec![];
+
+		self.consume_line_breaks();
+		while !self.is_eoi()
+			&& self.peek().kind != TokenKind::RightParen
+		{
+			arguments.push(self.parse_expression()?);
+			self.consume_line_breaks();
+			if !self.match_and_consume(TokenKind::Comma)
+			{
+				break;
+			}
+			self.consume_line_breaks();
+		}
+		let right_paren_token =
+			self.consume(TokenKind::RightParen, "`)`")?;
+
+		Ok(ast::Attribute {
+			attribute_token: Some(attribute_token),
+			left_paren_token: Some(left_paren_token),
+			arguments,
+			right_paren_token: Some(right_paren_token),
+		})
+	}
+
+	/// Parses an assert statement.
+	fn parse_assert_stmt(
+		&mut self,
+	) -> ParserResult<Box<ast::Statement<Expression>>>
+	{
+		let assert_token = self.previous();
+		let condition = self.parse_expression()?;
+
+		Ok(Box::new(ast::Statement::Assert(ast::AssertStmt {
+			assert_token: Some(assert_token),
+			condition,
+		})))
+	}
+
+	/// Parses a static assert statement.
+	fn parse_static_assert_stmt(
+		&mut self,
+	) -> ParserResult<Box<ast::Statement<Expression>>>
+	{
+		let assert_token = self.previous();
+		let condition = self.parse_expression()?;
+
+		Ok(Box::new(ast::Statement::StaticAssert(
+			ast::AssertStmt {
+				assert_token: Some(assert_token),
+				condition,
+			},
+		)))
+	}
+
+	/// Parses a defer statement.
+	/// Only the simple statement after `defer` is deferred.
+	fn parse_defer_stmt(
+		&mut self,
+	) -> ParserResult<Box<ast::Statement<Expression>>>
+	{
+		let defer_token = self.previous();
+		let statement = self.parse_simple_stmt()?;
+
+		Ok(Box::new(ast::Statement::Defer(ast::DeferStmt {
+			defer_token: Some(defer_token),
+			statement,
+		})))
+	}
+
 	/// Parses an expression statement.
 	fn parse_expression_stmt(
 		&mut self,
@@ -423,7 +650,7 @@ impl Parser
 		&mut self,
 	) -> ParserResult<ast::Expression>
 	{
-		self.pratt_parse(Precedence::Term)
+		self.pratt_parse(Precedence::Range)
 	}
 
 	/// Pratt parsing function
@@ -541,6 +768,30 @@ impl Parser
 				precedence: Precedence::Primary,
 				associativity: Associativity::None,
 			},
+			TokenKind::LeftBrace => ParseRule {
+				prefix_fn: Some(Parser::parse_block_expr),
+				infix_fn: None,
+				precedence: Precedence::None,
+				associativity: Associativity::None,
+			},
+			TokenKind::If => ParseRule {
+				prefix_fn: Some(Parser::parse_if_expr),
+				infix_fn: None,
+				precedence: Precedence::None,
+				associativity: Associativity::None,
+			},
+			TokenKind::LeftBracket => ParseRule {
+				prefix_fn: Some(Parser::parse_list_expr),
+				infix_fn: Some(Parser::parse_slice_expr),
+				precedence: Precedence::Primary,
+				associativity: Associativity::None,
+			},
+			TokenKind::Range => ParseRule {
+				prefix_fn: None,
+				infix_fn: Some(Parser::parse_range_expr),
+				precedence: Precedence::Range,
+				associativity: Associativity::None,
+			},
 			_ => ParseRule {
 				prefix_fn: None,
 				infix_fn: None,
@@ -566,6 +817,293 @@ impl Parser
 		}))
 	}
 
+	/// Parses a block expression.
+	/// The last statement of the block must be an expression
+	/// statement since it becomes the value of the block.
+	/// Braces whose first expression is followed by a colon
+	/// are parsed as a map expression instead. Empty braces
+	/// `{}` are a block without a value, never an empty map,
+	/// since the data types of an empty map can't be
+	/// inferred.
+	fn parse_block_expr(
+		&mut self,
+	) -> ParserResult<ast::Expression>
+	{
+		let left_brace_token = self.previous();
+		let mut statements = Vec::new();
+
+		self.consume_line_breaks();
+		if self.is_map_literal()
+		{
+			return self.parse_map_expr(left_brace_token);
+		}
+		if self.peek().kind == TokenKind::RightBrace
+		{
+			let right_brace_token = self.advance();
+			return Err(ParserError {
+				code: ParserErrorCode::EmptyBlock,
+				message: "expected an expression or a map entry \
+				          between `{` and `}`"
+					.to_string(),
+				hint: Some(
+					"`{}` is an empty block, and maps need at least \
+					 one entry like `{1: true}`, since their key \
+					 and value types are inferred from their entries"
+						.to_string(),
+				),
+				location: Location::Span(Span {
+					start: left_brace_token.span.start,
+					end: right_brace_token.span.end,
+				}),
+				source_id: self.source_id.clone(),
+			});
+		}
+		while !self.is_eoi()
+			&& self.peek().kind != TokenKind::RightBrace
+		{
+			statements.extend(self.parse_simple_stmts()?);
+			self.consume_line_breaks();
+		}
+		let right_brace_token =
+			self.consume(TokenKind::RightBrace, "`}`")?;
+
+		let expression = match statements
+			.pop()
+			.map(|stmt| *stmt)
+		{
+			Some(ast::Statement::Expression(stmt)) =>
+			{
+				Box::new(stmt.expression)
+			}
+			_ =>
+			{
+				return Err(ParserError {
+					code: ParserErrorCode::ExpectedExpression,
+					message: format!(
+						"expected an expression at the end of the \
+						 block, found {}",
+						right_brace_token.description(
+							1,
+							"lowercase",
+							None,
+							true,
+							true
+						)
+					),
+					hint: Some(
+						"the value of a block is the value of its \
+						 last expression"
+							.to_string(),
+					),
+					location: Location::Span(right_brace_token.span),
+					source_id: self.source_id.clone(),
+				});
+			}
+		};
+
+		Ok(ast::Expression::Block(ast::BlockExpr {
+			left_brace_token: Some(left_brace_token),
+			statements,
+			expression,
+			right_brace_token: Some(right_brace_token),
+		}))
+	}
+
+	/// Parses an if expression.
+	fn parse_if_expr(
+		&mut self,
+	) -> ParserResult<ast::Expression>
+	{
+		let if_token = self.previous();
+		let condition = Box::new(self.parse_expression()?);
+
+		self.consume_line_breaks();
+		let then_token =
+			self.consume(TokenKind::Then, "`then`")?;
+		self.consume_line_breaks();
+		let then_branch = Box::new(self.parse_expression()?);
+
+		self.consume_line_breaks();
+		let else_token =
+			self.consume(TokenKind::Else, "`else`")?;
+		self.consume_line_breaks();
+		let else_branch = Box::new(self.parse_expression()?);
+
+		Ok(ast::Expression::If(ast::IfExpr {
+			if_token: Some(if_token),
+			condition,
+			then_token: Some(then_token),
+			then_branch,
+			else_token: Some(else_token),
+			else_branch,
+		}))
+	}
+
+	/// Checks if the brace that was just consumed starts a
+	/// map literal. Colons are only used between the keys
+	/// and values of maps, so the braces hold a map if a
+	/// colon comes before the end of their first statement.
+	///
+	/// # Returns
+	///
+	/// `true` if the braces hold a map literal, otherwise
+	/// `false`.
+	fn is_map_literal(&self) -> bool
+	{
+		let mut depth = 0_usize;
+
+		for token in &self.tokens[self.current ..]
+		{
+			match token.kind
+			{
+				TokenKind::LeftParen
+				| TokenKind::LeftBrace
+				| TokenKind::LeftBracket => depth += 1,
+				TokenKind::RightParen | TokenKind::RightBracket
+					if depth > 0 =>
+				{
+					depth -= 1
+				}
+				TokenKind::RightBrace if depth > 0 => depth -= 1,
+				TokenKind::Colon if depth == 0 => return true,
+				TokenKind::RightParen
+				| TokenKind::RightBrace
+				| TokenKind::RightBracket
+				| TokenKind::NewLine
+				| TokenKind::SemiColon
+				| TokenKind::EndOfInput
+					if depth == 0 =>
+				{
+					return false;
+				}
+				_ => (),
+			}
+		}
+
+		false
+	}
+
+	/// Parses a map expression whose left brace was already
+	/// consumed.
+	/// Entries are separated by commas and a trailing comma
+	/// is allowed.
+	///
+	/// # Arguments
+	///
+	/// * left_brace_token - The left brace token.
+	///
+	/// # Returns
+	///
+	/// The parsed map expression or an error.
+	fn parse_map_expr(
+		&mut self,
+		left_brace_token: Token,
+	) -> ParserResult<ast::Expression>
+	{
+		let mut entries = Vec::new();
+
+		while !self.is_eoi()
+			&& self.peek().kind != TokenKind::RightBrace
+		{
+			let key = self.parse_expression()?;
+			let colon_token =
+				self.consume(TokenKind::Colon, "`:`")?;
+			self.consume_line_breaks();
+			let value = self.parse_expression()?;
+			entries.push(ast::MapEntry {
+				key,
+				colon_token: Some(colon_token),
+				value,
+			});
+
+			self.consume_line_breaks();
+			if !self.match_and_consume(TokenKind::Comma)
+			{
+				break;
+			}
+			self.consume_line_breaks();
+		}
+		let right_brace_token =
+			self.consume(TokenKind::RightBrace, "`}`")?;
+
+		Ok(ast::Expression::Map(ast::MapExpr {
+			left_brace_token: Some(left_brace_token),
+			entries,
+			right_brace_token: Some(right_brace_token),
+		}))
+	}
+
+	/// Parses a list expression.
+	/// Elements are separated by commas and a trailing comma
+	/// is allowed.
+	fn parse_list_expr(
+		&mut self,
+	) -> ParserResult<ast::Expression>
+	{
+		let left_bracket_token = self.previous();
+		let mut elements = Vec::new();
+
+		self.consume_line_breaks();
+		while !self.is_eoi()
+			&& self.peek().kind != TokenKind::RightBracket
+		{
+			elements.push(self.parse_expression()?);
+			self.consume_line_breaks();
+			if !self.match_and_consume(TokenKind::Comma)
+			{
+				break;
+			}
+			self.consume_line_breaks();
+		}
+		let right_bracket_token =
+			self.consume(TokenKind::RightBracket, "`]`")?;
+
+		Ok(ast::Expression::List(ast::ListExpr {
+			left_bracket_token: Some(left_bracket_token),
+			elements,
+			right_bracket_token: Some(right_bracket_token),
+		}))
+	}
+
+	/// Parses a slice expression.
+	fn parse_slice_expr(
+		&mut self,
+		target: ast::Expression,
+	) -> ParserResult<ast::Expression>
+	{
+		let left_bracket_token = self.previous();
+		self.consume_line_breaks();
+		let range = Box::new(self.parse_expression()?);
+		self.consume_line_breaks();
+		let right_bracket_token =
+			self.consume(TokenKind::RightBracket, "`]`")?;
+
+		Ok(ast::Expression::Slice(ast::SliceExpr {
+			target: Box::new(target),
+			left_bracket_token: Some(left_bracket_token),
+			range,
+			right_bracket_token: Some(right_bracket_token),
+		}))
+	}
+
+	/// Parses a range expression.
+	/// Ranges can't be chained, so the end of the range is
+	/// parsed with a higher precedence.
+	fn parse_range_expr(
+		&mut self,
+		start: ast::Expression,
+	) -> ParserResult<ast::Expression>
+	{
+		let operator_token = self.previous();
+		let end = Box::new(self.pratt_parse(Precedence::Term)?);
+
+		Ok(ast::Expression::Range(ast::RangeExpr {
+			start: Box::new(start),
+			operator_token: Some(operator_token),
+			end,
+		}))
+	}
+
 	/// Parses a unary expression.
 	fn parse_unary_expr(
 		&mut self,
@@ -583,6 +1121,21 @@ impl Parser
 				operator_token
 			),
 		};
+
+		// `-` is part of a signed integer literal right after
+		// it, so the minimum value of the data type is in range
+		if let Some((integer, suffix_start)) =
+			self.peek_signed_integer_literal()
+		{
+			let literal = self.advance();
+			return self.parse_integer_literal_expr(
+				literal,
+				integer,
+				Some(suffix_start),
+				Some(operator_token),
+			);
+		}
+
 		let right =
 			Box::new(self.pratt_parse(Precedence::Unary)?);
 
@@ -593,6 +1146,43 @@ impl Parser
 		}))
 	}
 
+	/// Peeks an integer literal with a signed suffix that a
+	/// preceding `-` can be folded into. The literal can't
+	/// be folded if it is the base of an exponent, since
+	/// `-2i8 ** 2` is `-(2i8 ** 2)`.
+	///
+	/// # Returns
+	///
+	/// The integer literal token and the start of its suffix,
+	/// otherwise `None`.
+	fn peek_signed_integer_literal(
+		&self,
+	) -> Option<(IntegerLiteralToken, Position)>
+	{
+		let literal = self.peek();
+		let TokenKind::Literal {
+			kind: LiteralTokenKind::Integer(integer),
+			suffix_start: Some(suffix_start),
+		} = literal.kind
+		else
+		{
+			return None;
+		};
+
+		let suffix = &literal.lexeme
+			[suffix_start.offset - literal.span.start.offset ..];
+		let is_exponent_base = self
+			.tokens
+			.get(self.current + 1)
+			.is_some_and(|next| next.kind == TokenKind::Exponent);
+		ternary!(
+			self.parse_suffix_string(suffix).0 == 'i'
+				&& !is_exponent_base,
+			Some((integer, suffix_start)),
+			None
+		)
+	}
+
 	/// Parses a binary expression.
 	fn parse_binary_expr(
 		&mut self,
@@ -656,6 +1246,7 @@ impl Parser
 				literal,
 				integer,
 				suffix_start,
+				None,
 			),
 			TokenKind::Literal {
 				kind: LiteralTokenKind::Float(float),
@@ -665,6 +1256,13 @@ impl Parser
 				float,
 				suffix_start,
 			),
+			TokenKind::Literal {
+				kind: LiteralTokenKind::Boolean(value),
+				..
+			} => Ok(ast::Expression::Literal(ast::LiteralExpr {
+				value: ast::Value::Bool(value),
+				token: Some(literal),
+			})),
 			_ => panic!(
 				"unexpected literal kind: {:?}",
 				literal.kind
@@ -707,6 +1305,8 @@ impl Parser
 				('f', width)
 			}
 			'd' => ('d', 64),
+			// bigints grow as wide as their value needs
+			'n' => ('n', 0),
 			_ => panic!("unexpected suffix: {}", suffix),
 		}
 	}
@@ -718,6 +1318,8 @@ impl Parser
 	/// * parent_token - The parent token.
 	/// * integer - The integer literal token.
 	/// * suffix_start - The suffix start position.
+	/// * negate_token - The `-` token folded into the
+	///   literal, if any.
 	///
 	/// # Returns
 	///
@@ -727,6 +1329,7 @@ impl Parser
 		parent_token: Token,
 		integer: IntegerLiteralToken,
 		suffix_start: Option<Position>,
+		negate_token: Option<Token>,
 	) -> ParserResult<ast::Expression>
 	{
 		// 100 + 0x12u32
@@ -757,13 +1360,58 @@ impl Parser
 				self.parse_suffix_string(suffix.as_str())
 			});
 
-		// parse the number with u64 since it's enough to hold
-		// all possible integer values
+		// remove the base prefix and the digit separators
 		let number_lexeme_without_prefix = ternary!(
 			integer.base == NumberBase::Decimal,
 			number_lexeme.as_str(),
 			&number_lexeme.as_str()[2 ..]
 		);
+		let digits = ternary!(
+			integer.has_digit_separator,
+			number_lexeme_without_prefix.replace('_', ""),
+			number_lexeme_without_prefix.to_string()
+		);
+		// a folded `-` becomes part of the literal token and
+		// its digits
+		let (token, digits) = match negate_token
+		{
+			Some(negate_token) if !digits.is_empty() => (
+				Token {
+					lexeme: format!("-{}", raw_number_lexeme).into(),
+					span: Span {
+						start: negate_token.span.start,
+						end: parent_token.span.end,
+					},
+					..parent_token
+				},
+				format!("-{}", digits),
+			),
+			_ => (parent_token, digits),
+		};
+		let data_type = ternary!(
+			suffix_start.is_none(),
+			"untyped int".to_owned(),
+			self.suffix_data_type_name(parsed_suffix)
+		);
+
+		// literals without suffix are untyped constants until
+		// they meet a typed operand
+		if suffix_start.is_none()
+		{
+			return Ok(ast::Expression::Literal(
+				ast::LiteralExpr {
+					value: ast::Value::UntypedInt(
+						self.parse_number_digits(
+							&token,
+							&digits,
+							integer.base,
+							&data_type,
+						)?,
+					),
+					token: Some(token),
+				},
+			));
+		}
 
 		match parsed_suffix
 		{
@@ -772,45 +1420,84 @@ impl Parser
 				// 0 means platform dependent integer
 				Ok(ast::Expression::Literal(ast::LiteralExpr {
 					value: ast::Value::Int(
-						number_lexeme_without_prefix.parse().unwrap(),
+						self.parse_number_digits(
+							&token,
+							&digits,
+							integer.base,
+							&data_type,
+						)?,
 					),
-					token: Some(parent_token),
+					token: Some(token),
 				}))
 			}
 			('i', 8) =>
 			{
 				Ok(ast::Expression::Literal(ast::LiteralExpr {
 					value: ast::Value::Int8(
-						number_lexeme_without_prefix.parse().unwrap(),
+						self.parse_number_digits(
+							&token,
+							&digits,
+							integer.base,
+							&data_type,
+						)?,
 					),
-					token: Some(parent_token),
+					token: Some(token),
 				}))
 			}
 			('i', 16) =>
 			{
 				Ok(ast::Expression::Literal(ast::LiteralExpr {
 					value: ast::Value::Int16(
-						number_lexeme_without_prefix.parse().unwrap(),
+						self.parse_number_digits(
+							&token,
+							&digits,
+							integer.base,
+							&data_type,
+						)?,
 					),
-					token: Some(parent_token),
+					token: Some(token),
 				}))
 			}
 			('i', 32) =>
 			{
 				Ok(ast::Expression::Literal(ast::LiteralExpr {
 					value: ast::Value::Int32(
-						number_lexeme_without_prefix.parse().unwrap(),
+						self.parse_number_digits(
+							&token,
+							&digits,
+							integer.base,
+							&data_type,
+						)?,
 					),
-					token: Some(parent_token),
+					token: Some(token),
 				}))
 			}
 			('i', 64) =>
 			{
 				Ok(ast::Expression::Literal(ast::LiteralExpr {
 					value: ast::Value::Int64(
-						number_lexeme_without_prefix.parse().unwrap(),
+						self.parse_number_digits(
+							&token,
+							&digits,
+							integer.base,
+							&data_type,
+						)?,
 					),
-					token: Some(parent_token),
+					token: Some(token),
+				}))
+			}
+			('i', 128) =>
+			{
+				Ok(ast::Expression::Literal(ast::LiteralExpr {
+					value: ast::Value::Int128(
+						self.parse_number_digits(
+							&token,
+							&digits,
+							integer.base,
+							&data_type,
+						)?,
+					),
+					token: Some(token),
 				}))
 			}
 			('u', 0) =>
@@ -818,72 +1505,143 @@ impl Parser
 				// 0 means platform dependent unsigned integer
 				Ok(ast::Expression::Literal(ast::LiteralExpr {
 					value: ast::Value::UInt(
-						number_lexeme_without_prefix.parse().unwrap(),
+						self.parse_number_digits(
+							&token,
+							&digits,
+							integer.base,
+							&data_type,
+						)?,
 					),
-					token: Some(parent_token),
+					token: Some(token),
 				}))
 			}
 			('u', 8) =>
 			{
 				Ok(ast::Expression::Literal(ast::LiteralExpr {
 					value: ast::Value::UInt8(
-						number_lexeme_without_prefix.parse().unwrap(),
+						self.parse_number_digits(
+							&token,
+							&digits,
+							integer.base,
+							&data_type,
+						)?,
 					),
-					token: Some(parent_token),
+					token: Some(token),
 				}))
 			}
 			('u', 16) =>
 			{
 				Ok(ast::Expression::Literal(ast::LiteralExpr {
 					value: ast::Value::UInt16(
-						number_lexeme_without_prefix.parse().unwrap(),
+						self.parse_number_digits(
+							&token,
+							&digits,
+							integer.base,
+							&data_type,
+						)?,
 					),
-					token: Some(parent_token),
+					token: Some(token),
 				}))
 			}
 			('u', 32) =>
 			{
 				Ok(ast::Expression::Literal(ast::LiteralExpr {
 					value: ast::Value::UInt32(
-						number_lexeme_without_prefix.parse().unwrap(),
+						self.parse_number_digits(
+							&token,
+							&digits,
+							integer.base,
+							&data_type,
+						)?,
 					),
-					token: Some(parent_token),
+					token: Some(token),
 				}))
 			}
 			('u', 64) =>
 			{
 				Ok(ast::Expression::Literal(ast::LiteralExpr {
 					value: ast::Value::UInt64(
-						number_lexeme_without_prefix.parse().unwrap(),
+						self.parse_number_digits(
+							&token,
+							&digits,
+							integer.base,
+							&data_type,
+						)?,
 					),
-					token: Some(parent_token),
+					token: Some(token),
 				}))
 			}
-			('f', 32) =>
+			('u', 128) =>
 			{
 				Ok(ast::Expression::Literal(ast::LiteralExpr {
-					value: ast::Value::Float32(
-						number_lexeme_without_prefix.parse().unwrap(),
+					value: ast::Value::UInt128(
+						self.parse_number_digits(
+							&token,
+							&digits,
+							integer.base,
+							&data_type,
+						)?,
 					),
-					token: Some(parent_token),
+					token: Some(token),
 				}))
 			}
-			('f', 64) =>
+			('f', 16) =>
 			{
 				Ok(ast::Expression::Literal(ast::LiteralExpr {
-					value: ast::Value::Double(
-						number_lexeme_without_prefix.parse().unwrap(),
+					value: ast::Value::Float16(
+						self.parse_float_digits(
+							&token,
+							&digits,
+							integer.base,
+							16,
+							&data_type,
+						)? as f16,
 					),
-					token: Some(parent_token),
+					token: Some(token),
 				}))
 			}
-			('d', 64) =>
+			('f', 32) =>
 			{
 				Ok(ast::Expression::Literal(ast::LiteralExpr {
-					value: ast::Value::Double(
-						number_lexeme_without_prefix.parse().unwrap(),
+					value: ast::Value::Float32(
+						self.parse_float_digits(
+							&token,
+							&digits,
+							integer.base,
+							32,
+							&data_type,
+						)? as f32,
 					),
-					token: Some(parent_token),
+					token: Some(token),
+				}))
+			}
+			('f', 64) | ('d', 64) =>
+			{
+				Ok(ast::Expression::Literal(ast::LiteralExpr {
+					value: ast::Value::Double(
+						self.parse_float_digits(
+							&token,
+							&digits,
+							integer.base,
+							64,
+							&data_type,
+						)?,
+					),
+					token: Some(token),
+				}))
+			}
+			('n', 0) =>
+			{
+				Ok(ast::Expression::Literal(ast::LiteralExpr {
+					value: ast::Value::BigInt(
+						self.parse_number_digits(
+							&token,
+							&digits,
+							integer.base,
+							&data_type,
+						)?,
+					),
+					token: Some(token),
 				}))
 			}
 			_ => panic!("unexpected suffix: {:?}", parsed_suffix),
@@ -904,23 +1662,25 @@ impl Parser
 	fn parse_float_literal_expr(
 		&self,
 		parent_token: Token,
-		_float: FloatLiteralToken,
+		float: FloatLiteralToken,
 		suffix_start: Option<Position>,
 	) -> ParserResult<ast::Expression>
 	{
 		let raw_number_lexeme = parent_token.clone().lexeme;
+		let suffix_start_relative =
+			suffix_start.map(|suffix_start| {
+				suffix_start.offset - parent_token.span.start.offset
+			});
 
 		// remove the suffix from the lexeme
-		let number_lexeme = suffix_start.map_or(
-			raw_number_lexeme.clone(),
-			|suffix_start| {
-				raw_number_lexeme[.. suffix_start.offset].into()
-			},
-		);
+		let number_lexeme = raw_number_lexeme
+			[.. suffix_start_relative
+				.unwrap_or_else(|| raw_number_lexeme.len())]
+			.to_smolstr();
 
 		// get the suffix lexeme
-		let suffix = suffix_start.map(|suffix_start| {
-			raw_number_lexeme[suffix_start.offset ..].to_smolstr()
+		let suffix = suffix_start_relative.map(|start| {
+			raw_number_lexeme[start ..].to_smolstr()
 		});
 
 		let parsed_suffix = suffix
@@ -928,36 +1688,916 @@ impl Parser
 				self.parse_suffix_string(suffix.as_str())
 			});
 
+		// remove the base prefix and the digit separators
+		let number_lexeme_without_prefix = ternary!(
+			float.base == NumberBase::Hexadecimal,
+			&number_lexeme.as_str()[2 ..],
+			number_lexeme.as_str()
+		);
+		let digits = ternary!(
+			float.has_digit_separator,
+			number_lexeme_without_prefix.replace('_', ""),
+			number_lexeme_without_prefix.to_string()
+		);
+		let data_type = ternary!(
+			suffix_start.is_none(),
+			"untyped float".to_owned(),
+			self.suffix_data_type_name(parsed_suffix)
+		);
+		// the value is rounded once, to the data type of the
+		// literal, so it is exact in 64 bits
+		let value = self.parse_float_digits(
+			&parent_token,
+			&digits,
+			float.base,
+			ternary!(parsed_suffix.0 == 'f', parsed_suffix.1, 64),
+			&data_type,
+		)?;
+
+		// literals without suffix are untyped constants until
+		// they meet a typed operand
+		if suffix_start.is_none()
+		{
+			return Ok(ast::Expression::Literal(
+				ast::LiteralExpr {
+					value: ast::Value::UntypedFloat(value),
+					token: Some(parent_token),
+				},
+			));
+		}
+
 		match parsed_suffix
 		{
-			('f', 32) =>
+			('f', 16) =>
 			{
 				Ok(ast::Expression::Literal(ast::LiteralExpr {
-					value: ast::Value::Float32(
-						number_lexeme.parse().unwrap(),
-					),
+					value: ast::Value::Float16(value as f16),
 					token: Some(parent_token),
 				}))
 			}
-			('f', 64) =>
+			('f', 32) =>
 			{
 				Ok(ast::Expression::Literal(ast::LiteralExpr {
-					value: ast::Value::Double(
-						number_lexeme.parse().unwrap(),
-					),
+					value: ast::Value::Float32(value as f32),
 					token: Some(parent_token),
 				}))
 			}
-			('d', 64) =>
+			('f', 64) | ('d', 64) =>
 			{
 				Ok(ast::Expression::Literal(ast::LiteralExpr {
-					value: ast::Value::Double(
-						number_lexeme.parse().unwrap(),
-					),
+					value: ast::Value::Double(value),
 					token: Some(parent_token),
 				}))
 			}
 			_ => panic!("unexpected suffix: {:?}", parsed_suffix),
 		}
 	}
+
+	/// Get the name of the data type of a number literal
+	/// suffix.
+	///
+	/// # Arguments
+	///
+	/// * suffix - The parsed suffix and width.
+	///
+	/// # Returns
+	///
+	/// The name of the data type.
+	fn suffix_data_type_name(
+		&self,
+		suffix: (char, u8),
+	) -> String
+	{
+		match suffix
+		{
+			('i', 0) => "int".to_owned(),
+			('u', 0) => "uint".to_owned(),
+			('i', width) => format!("int{}", width),
+			('u', width) => format!("uint{}", width),
+			('f', 64) | ('d', _) => "double".to_owned(),
+			('f', width) => format!("float{}", width),
+			_ => "bigint".to_owned(),
+		}
+	}
+
+	/// Create an error for a number literal whose value does
+	/// not fit in its data type.
+	///
+	/// # Arguments
+	///
+	/// * token - The literal token.
+	/// * kind - The kind of the literal, `integer` or
+	///   `float`.
+	/// * data_type - The name of the data type.
+	///
+	/// # Returns
+	///
+	/// The parser error.
+	fn create_out_of_range_error(
+		&self,
+		token: &Token,
+		kind: &str,
+		data_type: &str,
+	) -> ParserError
+	{
+		// only bigints are wider than untyped integers
+		let tip = ternary!(
+			data_type == "untyped int",
+			"add the `n` suffix to make it a bigint",
+			"use a wider data type"
+		);
+
+		ParserError {
+			code: ParserErrorCode::LiteralOutOfRange,
+			message: format!(
+				"{} literal out of range for {}",
+				kind, data_type
+			),
+			hint: Some(format!(
+				"the value of `{}` does not fit in {}, {}",
+				token.lexeme, data_type, tip
+			)),
+			location: Location::Span(token.span),
+			source_id: self.source_id.clone(),
+		}
+	}
+
+	/// Parse the digits of a number literal in the given
+	/// base. Literals without digits like `0x` are zero.
+	///
+	/// # Arguments
+	///
+	/// * token - The literal token.
+	/// * digits - The digits without prefix and separators.
+	/// * base - The base of the digits.
+	/// * data_type - The name of the data type.
+	///
+	/// # Returns
+	///
+	/// The parsed number or an error if it does not fit in
+	/// the data type.
+	fn parse_number_digits<T>(
+		&self,
+		token: &Token,
+		digits: &str,
+		base: NumberBase,
+		data_type: &str,
+	) -> ParserResult<T>
+	where
+		T: Num,
+	{
+		if digits.is_empty()
+		{
+			return Ok(T::zero());
+		}
+
+		let radix = match base
+		{
+			NumberBase::Binary => 2,
+			NumberBase::Octal => 8,
+			NumberBase::Decimal => 10,
+			NumberBase::Hexadecimal => 16,
+		};
+		// the lexer only accepts digits of the base, so the
+		// value is too large if they can't be parsed
+		T::from_str_radix(digits, radix).map_err(|_| {
+			self.create_out_of_range_error(
+				token, "integer", data_type,
+			)
+		})
+	}
+
+	/// Parse the digits of a float literal, rounded to the
+	/// nearest float of the given width. Binary and octal
+	/// digits are integer literals with a float suffix.
+	///
+	/// # Arguments
+	///
+	/// * token - The literal token.
+	/// * digits - The digits without prefix and separators.
+	/// * base - The base of the digits.
+	/// * width - The width of the float, 16, 32 or 64.
+	/// * data_type - The name of the data type.
+	///
+	/// # Returns
+	///
+	/// The parsed number, which is exact in 64 bits, or an
+	/// error if it is too large for the data type.
+	fn parse_float_digits(
+		&self,
+		token: &Token,
+		digits: &str,
+		base: NumberBase,
+		width: u8,
+		data_type: &str,
+	) -> ParserResult<f64>
+	{
+		let (precision, max_exponent) = match width
+		{
+			16 => (11, 15),
+			32 => (24, 127),
+			_ => (53, 1023),
+		};
+
+		let value = match base
+		{
+			NumberBase::Decimal => Some(match width
+			{
+				16 => digits.parse::<f16>().unwrap() as f64,
+				32 => digits.parse::<f32>().unwrap() as f64,
+				_ => digits.parse::<f64>().unwrap(),
+			}),
+			NumberBase::Hexadecimal => self
+				.parse_hex_float_digits(
+					digits,
+					precision,
+					max_exponent,
+				),
+			// the integer is rounded like a hexadecimal float
+			// so it is only rounded once
+			NumberBase::Binary | NumberBase::Octal =>
+			{
+				let integer = self.parse_number_digits::<BigUint>(
+					token, digits, base, data_type,
+				)?;
+				self.parse_hex_float_digits(
+					&format!("{:x}p0", integer),
+					precision,
+					max_exponent,
+				)
+			}
+		};
+
+		// `inf` and `nan` are the only literals whose value is
+		// not finite
+		match value
+		{
+			Some(value)
+				if value.is_finite()
+					|| matches!(digits, "inf" | "nan") =>
+			{
+				Ok(value)
+			}
+			_ => Err(self.create_out_of_range_error(
+				token, "float", data_type,
+			)),
+		}
+	}
+
+	/// Parse the digits of a hexadecimal float literal like
+	/// `1.8p3`. The value is the hexadecimal mantissa
+	/// multiplied by two to the power of the decimal
+	/// exponent, rounded to nearest, ties to even, to a float
+	/// with the given precision.
+	///
+	/// # Arguments
+	///
+	/// * digits - The digits without prefix and separators.
+	/// * precision - The number of significant bits of the
+	///   float, including the implicit bit.
+	/// * max_exponent - The largest exponent of the float.
+	///
+	/// # Returns
+	///
+	/// The parsed number, which is exact in 64 bits, or
+	/// `None` if it is too large for the float.
+	fn parse_hex_float_digits(
+		&self,
+		digits: &str,
+		precision: i64,
+		max_exponent: i64,
+	) -> Option<f64>
+	{
+		let (mantissa, exponent) =
+			digits.split_once(['p', 'P']).unwrap();
+		let (int_part, fractional_part) =
+			mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+		let mantissa = BigUint::from_str_radix(
+			&format!("{}{}", int_part, fractional_part),
+			16,
+		)
+		.unwrap_or_default();
+		if mantissa.is_zero()
+		{
+			return Some(0.0);
+		}
+
+		// exponents too large for any float saturate, the
+		// value overflows or becomes zero either way. Every
+		// fractional digit shifts the mantissa by 4 bits.
+		let exponent = exponent
+			.parse::<i64>()
+			.unwrap_or(ternary!(
+				exponent.starts_with('-'),
+				i64::MIN,
+				i64::MAX
+			))
+			.clamp(-(1 << 40), 1 << 40)
+			- 4 * fractional_part.len() as i64;
+
+		// exponents of the highest bit of the value and of
+		// the lowest bit that fits in the float
+		let bits = mantissa.bits() as i64;
+		let highest = bits - 1 + exponent;
+		if highest > max_exponent
+		{
+			return None;
+		}
+		let mut lowest = (highest - precision + 1)
+			.max(2 - max_exponent - precision);
+
+		let shift = lowest - exponent;
+		if shift > bits
+		{
+			// less than half of the smallest float
+			return Some(0.0);
+		}
+		let mut significand = if shift <= 0
+		{
+			mantissa << (-shift as u64)
+		}
+		else
+		{
+			let kept = &mantissa >> (shift as u64);
+			let dropped = &mantissa - (&kept << (shift as u64));
+			let half = BigUint::from(1_u8) << (shift as u64 - 1);
+			ternary!(
+				dropped > half || (dropped == half && kept.bit(0)),
+				kept + 1_u8,
+				kept
+			)
+		};
+
+		// rounding up can carry into a new bit
+		if significand.bits() as i64 > precision
+		{
+			significand >>= 1_u8;
+			lowest += 1;
+		}
+		if lowest + significand.bits() as i64 - 1 > max_exponent
+		{
+			return None;
+		}
+
+		// the significand and every power of two below are
+		// exact, so the value is only rounded above
+		let significand = significand.to_u64().unwrap() as f64;
+		let lowest = lowest as i32;
+		Some(ternary!(
+			lowest < -1000,
+			significand
+				* 2f64.powi(-1000)
+				* 2f64.powi(lowest + 1000),
+			significand * 2f64.powi(lowest)
+		))
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::parser::lexer::Lexer;
+
+	/// Parse the source code as a module that is not the
+	/// main module, so its statements stay at the top level
+	fn parse(
+		source_code: &str,
+	) -> ParserResult<ast::Module<Expression>>
+	{
+		let tokens = Lexer::tokenize(
+			"string".into(),
+			source_code.to_owned(),
+		)
+		.unwrap();
+		Parser::parse("string".into(), false, tokens)
+	}
+
+	/// Parse the source code and get the expression of its
+	/// only expression statement
+	fn parse_expression(source_code: &str) -> Expression
+	{
+		let mut module = parse(source_code).unwrap();
+		assert_eq!(module.statements.len(), 1);
+		match *module.statements.remove(0)
+		{
+			ast::Statement::Expression(stmt) => stmt.expression,
+			statement => panic!(
+				"expected an expression statement, found {:?}",
+				statement
+			),
+		}
+	}
+
+	/// Parse the source code and get the value of its only
+	/// literal
+	fn parse_value(source_code: &str) -> ast::Value
+	{
+		match parse_expression(source_code)
+		{
+			Expression::Literal(literal) => literal.value,
+			expression => panic!(
+				"expected a literal expression, found {:?}",
+				expression
+			),
+		}
+	}
+
+	#[test]
+	fn test_parse_integer_literal_in_range()
+	{
+		assert!(matches!(
+			parse_value("255u8"),
+			ast::Value::UInt8(255)
+		));
+		assert!(matches!(
+			parse_value("1_000u16"),
+			ast::Value::UInt16(1000)
+		));
+		assert!(matches!(
+			parse_value("0xffff_ffff_ffff_ffffu64"),
+			ast::Value::UInt64(u64::MAX)
+		));
+		assert!(matches!(
+			parse_value("0b1111_1111i16"),
+			ast::Value::Int16(255)
+		));
+	}
+
+	#[test]
+	fn test_parse_literal_out_of_range()
+	{
+		for source_code in &[
+			"300u8",
+			"1_000u8",
+			"128i8",
+			"0xffff_ffff_ffff_ffff_fu64",
+			"0o1_000_000u16",
+			"70000f16",
+			"1e39f32",
+			"1e400",
+			"0x1p99999",
+			"0x1p128f32",
+			"0x1.ffep15f16",
+			"0x1_0000_0000_0000_0000_0000_0000_0000_0000",
+		]
+		{
+			let error = parse(source_code).unwrap_err();
+			assert_eq!(
+				error.code,
+				ParserErrorCode::LiteralOutOfRange,
+				"{}",
+				source_code
+			);
+		}
+
+		// the special values are not finite but in range
+		assert!(matches!(
+			parse_value("inf"),
+			ast::Value::UntypedFloat(value) if value.is_infinite()
+		));
+		assert!(matches!(
+			parse_value("nan"),
+			ast::Value::UntypedFloat(value) if value.is_nan()
+		));
+	}
+
+	#[test]
+	fn test_parse_negative_integer_literal()
+	{
+		// `-` is part of a signed literal, so the minimum value
+		// of the data type can be written
+		assert!(matches!(
+			parse_value("-128i8"),
+			ast::Value::Int8(i8::MIN)
+		));
+		assert!(matches!(
+			parse_value("-0x80i8"),
+			ast::Value::Int8(i8::MIN)
+		));
+		assert!(matches!(
+			parse_value(
+				"-170141183460469231731687303715884105728i128"
+			),
+			ast::Value::Int128(i128::MIN)
+		));
+		let Expression::Literal(literal) =
+			parse_expression("-1_000i16")
+		else
+		{
+			panic!("expected a literal expression");
+		};
+		assert_eq!(literal.token.unwrap().lexeme, "-1_000i16");
+
+		for source_code in &[
+			"-129i8",
+			"-170141183460469231731687303715884105729i128",
+		]
+		{
+			let error = parse(source_code).unwrap_err();
+			assert_eq!(
+				error.code,
+				ParserErrorCode::LiteralOutOfRange,
+				"{}",
+				source_code
+			);
+		}
+
+		// unsigned, untyped and exponent base literals are
+		// negated by a unary expression
+		for source_code in
+			&["-1u8", "-1", "-1.5f32", "-2i8 ** 2"]
+		{
+			assert!(
+				matches!(
+					parse_expression(source_code),
+					Expression::Unary(_)
+				),
+				"{}",
+				source_code
+			);
+		}
+	}
+
+	#[test]
+	fn test_parse_hex_float_literal()
+	{
+		assert!(matches!(
+			parse_value("0x1.8p3"),
+			ast::Value::UntypedFloat(12.0)
+		));
+		assert!(matches!(
+			parse_value("0x1.ffcp15f16"),
+			ast::Value::Float16(65504.0)
+		));
+		// rounding to 64 bits first would round the tie down
+		assert!(matches!(
+			parse_value("0x1.0000010000000001p0f32"),
+			ast::Value::Float32(value)
+				if value == 1.0 + f32::EPSILON
+		));
+		// ties round to the even neighbor
+		assert!(matches!(
+			parse_value("0x1.000001p0f32"),
+			ast::Value::Float32(1.0)
+		));
+		// subnormal values keep the bits that fit
+		assert!(matches!(
+			parse_value("0x3p-1076"),
+			ast::Value::UntypedFloat(value)
+				if value == f64::from_bits(1)
+		));
+		assert!(matches!(
+			parse_value("0x1p-1076"),
+			ast::Value::UntypedFloat(0.0)
+		));
+		assert!(matches!(
+			parse_value("0b11f32"),
+			ast::Value::Float32(3.0)
+		));
+	}
+
+	#[test]
+	fn test_parse_block_expr()
+	{
+		let Expression::Block(block) =
+			parse_expression("{ echo 1; 2 }")
+		else
+		{
+			panic!("expected a block expression");
+		};
+		assert_eq!(block.statements.len(), 1);
+		assert!(matches!(
+			*block.statements[0],
+			ast::Statement::Echo(_)
+		));
+		assert!(matches!(
+			*block.expression,
+			Expression::Literal(_)
+		));
+
+		// line breaks inside braces are not significant
+		let Expression::Block(block) =
+			parse_expression("{\n\techo 1\n\t2\n}")
+		else
+		{
+			panic!("expected a block expression");
+		};
+		assert_eq!(block.statements.len(), 1);
+	}
+
+	#[test]
+	fn test_parse_block_expr_without_value()
+	{
+		for source_code in &["{ echo 1 }", "{ 1; echo 2 }"]
+		{
+			let error = parse(source_code).unwrap_err();
+			assert_eq!(
+				error.code,
+				ParserErrorCode::ExpectedExpression
+			);
+		}
+	}
+
+	#[test]
+	fn test_parse_if_expr()
+	{
+		let Expression::If(if_expr) =
+			parse_expression("if true then 1 else { 2 }")
+		else
+		{
+			panic!("expected an if expression");
+		};
+		assert!(matches!(
+			*if_expr.condition,
+			Expression::Literal(_)
+		));
+		assert!(matches!(
+			*if_expr.else_branch,
+			Expression::Block(_)
+		));
+
+		// the keywords can start a new line
+		assert!(matches!(
+			parse_expression("if true\nthen 1\nelse 2"),
+			Expression::If(_)
+		));
+	}
+
+	#[test]
+	fn test_parse_if_expr_without_branch()
+	{
+		for source_code in &["if true else 2", "if true then 1"]
+		{
+			let error = parse(source_code).unwrap_err();
+			assert_eq!(
+				error.code,
+				ParserErrorCode::ExpectedToken
+			);
+		}
+	}
+
+	#[test]
+	fn test_parse_list_expr()
+	{
+		let Expression::List(list) =
+			parse_expression("[1, 2, 3,]")
+		else
+		{
+			panic!("expected a list expression");
+		};
+		assert_eq!(list.elements.len(), 3);
+
+		// the element type of an empty list is checked later
+		let Expression::List(list) = parse_expression("[]")
+		else
+		{
+			panic!("expected a list expression");
+		};
+		assert!(list.elements.is_empty());
+
+		// line breaks inside brackets are not significant
+		let Expression::List(list) =
+			parse_expression("[\n\t[1],\n\t[2]\n]")
+		else
+		{
+			panic!("expected a list expression");
+		};
+		assert_eq!(list.elements.len(), 2);
+		assert!(matches!(
+			list.elements[0],
+			Expression::List(_)
+		));
+	}
+
+	#[test]
+	fn test_parse_list_expr_errors()
+	{
+		assert_eq!(
+			parse("[1, 2").unwrap_err().code,
+			ParserErrorCode::ExpectedToken
+		);
+		assert_eq!(
+			parse("[,]").unwrap_err().code,
+			ParserErrorCode::ExpectedExpression
+		);
+	}
+
+	#[test]
+	fn test_parse_map_expr()
+	{
+		let Expression::Map(map) =
+			parse_expression("{1: true, 2: false,}")
+		else
+		{
+			panic!("expected a map expression");
+		};
+		assert_eq!(map.entries.len(), 2);
+		assert!(matches!(
+			map.entries[1].key,
+			Expression::Literal(_)
+		));
+
+		// a colon before the end of the first statement makes
+		// the braces a map, otherwise they are a block
+		assert!(matches!(
+			parse_expression("{\n\t1: { 2 }\n}"),
+			Expression::Map(_)
+		));
+		assert!(matches!(
+			parse_expression("{ { 1: 2 } }"),
+			Expression::Block(_)
+		));
+	}
+
+	#[test]
+	fn test_parse_map_expr_errors()
+	{
+		for source_code in &["{1: 2", "{1: 2; 3}", "{1: 2, 3}"]
+		{
+			assert_eq!(
+				parse(source_code).unwrap_err().code,
+				ParserErrorCode::ExpectedToken,
+				"{}",
+				source_code
+			);
+		}
+
+		// an empty map can't be told apart from an empty block
+		for source_code in &["{}", "{\n}"]
+		{
+			let error = parse(source_code).unwrap_err();
+			assert_eq!(
+				error.code,
+				ParserErrorCode::EmptyBlock,
+				"{}",
+				source_code
+			);
+			assert!(error.hint.unwrap().contains("`{1: true}`"));
+		}
+	}
+
+	#[test]
+	fn test_parse_range_and_slice_expr()
+	{
+		let Expression::Range(range) =
+			parse_expression("0..1 + 2")
+		else
+		{
+			panic!("expected a range expression");
+		};
+		// the end binds tighter than the range
+		assert!(matches!(*range.end, Expression::Binary(_)));
+
+		let Expression::Slice(slice) =
+			parse_expression("[1, 2, 3][0..2][1..2]")
+		else
+		{
+			panic!("expected a slice expression");
+		};
+		assert!(matches!(*slice.target, Expression::Slice(_)));
+		assert!(matches!(*slice.range, Expression::Range(_)));
+
+		assert_eq!(
+			parse("[1, 2][0..1").unwrap_err().code,
+			ParserErrorCode::ExpectedToken
+		);
+		assert_eq!(
+			parse("0..").unwrap_err().code,
+			ParserErrorCode::ExpectedExpression
+		);
+	}
+
+	#[test]
+	fn test_parse_defer_stmt()
+	{
+		// only the statement right after `defer` is deferred
+		let module = parse("defer echo 1; echo 2").unwrap();
+		assert_eq!(module.statements.len(), 2);
+		let ast::Statement::Defer(defer_stmt) =
+			&*module.statements[0]
+		else
+		{
+			panic!("expected a defer statement");
+		};
+		assert!(matches!(
+			*defer_stmt.statement,
+			ast::Statement::Echo(_)
+		));
+		assert!(matches!(
+			*module.statements[1],
+			ast::Statement::Echo(_)
+		));
+
+		// nested defer statements are rejected by the semantic
+		// checker
+		let module = parse("defer defer echo 1").unwrap();
+		let ast::Statement::Defer(defer_stmt) =
+			&*module.statements[0]
+		else
+		{
+			panic!("expected a defer statement");
+		};
+		assert!(matches!(
+			*defer_stmt.statement,
+			ast::Statement::Defer(_)
+		));
+
+		assert_eq!(
+			parse("defer").unwrap_err().code,
+			ParserErrorCode::ExpectedExpression
+		);
+	}
+
+	#[test]
+	fn test_parse_assert_stmt()
+	{
+		let module =
+			parse("assert true\nstatic_assert false").unwrap();
+		assert!(matches!(
+			*module.statements[0],
+			ast::Statement::Assert(_)
+		));
+		assert!(matches!(
+			*module.statements[1],
+			ast::Statement::StaticAssert(_)
+		));
+
+		for source_code in &["assert", "static_assert"]
+		{
+			assert_eq!(
+				parse(source_code).unwrap_err().code,
+				ParserErrorCode::ExpectedExpression,
+				"{}",
+				source_code
+			);
+		}
+	}
+
+	#[test]
+	fn test_parse_attributed_stmt()
+	{
+		let module = parse("@inline echo 1").unwrap();
+		let ast::Statement::Attributed(attributed_stmt) =
+			&*module.statements[0]
+		else
+		{
+			panic!("expected an attributed statement");
+		};
+		assert_eq!(attributed_stmt.attributes.len(), 1);
+		assert_eq!(
+			attributed_stmt.attributes[0].name(),
+			Some("inline")
+		);
+		assert!(matches!(
+			*attributed_stmt.statement,
+			ast::Statement::Echo(_)
+		));
+
+		// attributes can be on the lines before the statement
+		let module =
+			parse("@test\n@deprecated(1, 2)\necho 1").unwrap();
+		assert_eq!(module.statements.len(), 1);
+		let ast::Statement::Attributed(attributed_stmt) =
+			&*module.statements[0]
+		else
+		{
+			panic!("expected an attributed statement");
+		};
+		assert_eq!(attributed_stmt.attributes.len(), 2);
+		assert_eq!(
+			attributed_stmt.attributes[1].name(),
+			Some("deprecated")
+		);
+		assert_eq!(
+			attributed_stmt.attributes[1].arguments.len(),
+			2
+		);
+
+		// the arguments must start right after the name
+		let module = parse("@inline (1)").unwrap();
+		let ast::Statement::Attributed(attributed_stmt) =
+			&*module.statements[0]
+		else
+		{
+			panic!("expected an attributed statement");
+		};
+		assert!(
+			attributed_stmt.attributes[0].arguments.is_empty()
+		);
+		assert!(matches!(
+			&*attributed_stmt.statement,
+			ast::Statement::Expression(expression_stmt)
+				if matches!(
+					expression_stmt.expression,
+					Expression::Grouping(_)
+				)
+		));
+
+		assert_eq!(
+			parse("@inline(1 echo 1").unwrap_err().code,
+			ParserErrorCode::ExpectedToken
+		);
+	}
 }
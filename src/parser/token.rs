@@ -28,6 +28,11 @@ pub struct IntegerLiteralToken
 	/// For example, some integer doesn't have integer part
 	/// like `0x`, `0b`, `0ou8`. But they're valid integer.
 	pub has_integer_part: bool,
+	/// Status if the integer contains `_` digit separators.
+	/// For example, `1_000_000` or `0xffff_ffff`. Together
+	/// with the lexeme, it lets a formatter reproduce the
+	/// original spelling.
+	pub has_digit_separator: bool,
 }
 
 /// Float literal token.
@@ -37,7 +42,11 @@ pub struct FloatLiteralToken
 	/// Base of the number.
 	pub base: NumberBase,
 	/// Status if the float has exponent part.
+	/// Hexadecimal floats always have one, like `0x1.8p3`.
 	pub has_exponent_part: bool,
+	/// Status if the float contains `_` digit separators.
+	/// For example, `1_000.5` or `1e1_0`.
+	pub has_digit_separator: bool,
 }
 
 /// Literal token.
@@ -50,6 +59,8 @@ pub enum LiteralTokenKind
 	Integer(IntegerLiteralToken),
 	/// Float literal
 	Float(FloatLiteralToken),
+	/// Boolean literal (`true` or `false`)
+	Boolean(bool),
 }
 
 /// Possible token kinds.
@@ -79,20 +90,46 @@ pub enum TokenKind
 	Modulo,
 	/// Exponent
 	Exponent,
+	/// Range
+	Range,
 	// other statements' tokens
 	/// Echo
 	Echo,
 	/// Function
 	Function,
+	/// If
+	If,
+	/// Then
+	Then,
+	/// Else
+	Else,
+	/// Defer
+	Defer,
+	/// Assert
+	Assert,
+	/// Static assert
+	StaticAssert,
+	/// Attribute name with its `@` prefix
+	Attribute,
 	// Other tokens
 	/// Semicolon
 	SemiColon,
 	/// Colon
 	Colon,
+	/// Comma
+	Comma,
 	/// Left Parenthesis
 	LeftParen,
 	/// Right Parenthesis
 	RightParen,
+	/// Left Brace
+	LeftBrace,
+	/// Right Brace
+	RightBrace,
+	/// Left Bracket
+	LeftBracket,
+	/// Right Bracket
+	RightBracket,
 	/// Single line comment
 	SingleLineComment,
 	/// Whitespace sequence
@@ -156,6 +193,7 @@ impl Token
 			{
 				LiteralTokenKind::Integer(_) => "int",
 				LiteralTokenKind::Float(_) => "float",
+				LiteralTokenKind::Boolean(_) => "bool",
 			},
 			TokenKind::Identifier => "identifier",
 			TokenKind::Add
@@ -163,12 +201,26 @@ impl Token
 			| TokenKind::Multiply
 			| TokenKind::Divide
 			| TokenKind::Modulo
-			| TokenKind::Exponent => "operator",
-			TokenKind::Echo | TokenKind::Function => "keyword",
+			| TokenKind::Exponent
+			| TokenKind::Range => "operator",
+			TokenKind::Echo
+			| TokenKind::Function
+			| TokenKind::If
+			| TokenKind::Then
+			| TokenKind::Else
+			| TokenKind::Defer
+			| TokenKind::Assert
+			| TokenKind::StaticAssert => "keyword",
+			TokenKind::Attribute => "attribute",
 			TokenKind::SemiColon => "semicolon",
 			TokenKind::Colon => "colon",
+			TokenKind::Comma => "comma",
 			TokenKind::LeftParen => "left-paren",
 			TokenKind::RightParen => "right-paren",
+			TokenKind::LeftBrace => "left-brace",
+			TokenKind::RightBrace => "right-brace",
+			TokenKind::LeftBracket => "left-bracket",
+			TokenKind::RightBracket => "right-bracket",
 			TokenKind::SingleLineComment => "single-line-comment",
 			TokenKind::Whitespace => "whitespace",
 			TokenKind::Tab => "tab",
@@ -205,6 +257,7 @@ mod tests
 					IntegerLiteralToken {
 						base: NumberBase::Decimal,
 						has_integer_part: true,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: None,
@@ -234,6 +287,7 @@ mod tests
 					IntegerLiteralToken {
 						base: NumberBase::Decimal,
 						has_integer_part: true,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: None,
@@ -272,6 +326,7 @@ mod tests
 					IntegerLiteralToken {
 						base: NumberBase::Decimal,
 						has_integer_part: true,
+						has_digit_separator: false,
 					},
 				),
 				suffix_start: None,
@@ -304,6 +359,7 @@ mod tests
 				kind: LiteralTokenKind::Float(FloatLiteralToken {
 					base: NumberBase::Decimal,
 					has_exponent_part: false,
+					has_digit_separator: false,
 				}),
 				suffix_start: None,
 			},
@@ -329,6 +385,57 @@ mod tests
 			"a floating-point literal"
 		);
 
+		let token = Token::new(
+			TokenKind::Literal {
+				kind: LiteralTokenKind::Boolean(true),
+				suffix_start: None,
+			},
+			Span::new(
+				Position {
+					line: 1,
+					column: 1,
+					char_index: 0,
+					offset: 0,
+				},
+				Position {
+					line: 1,
+					column: 5,
+					char_index: 4,
+					offset: 4,
+				},
+			),
+			SmolStr::new("true"),
+			SmolStr::new("mabel://stdin"),
+		);
+		assert_eq!(
+			token.description(1, "lowercase", None, true, false),
+			"a boolean literal"
+		);
+
+		let token = Token::new(
+			TokenKind::LeftBrace,
+			Span::new(
+				Position {
+					line: 1,
+					column: 1,
+					char_index: 0,
+					offset: 0,
+				},
+				Position {
+					line: 1,
+					column: 2,
+					char_index: 1,
+					offset: 1,
+				},
+			),
+			SmolStr::new("{"),
+			SmolStr::new("mabel://stdin"),
+		);
+		assert_eq!(
+			token.description(1, "lowercase", None, true, false),
+			"a left brace"
+		);
+
 		let token = Token::new(
 			TokenKind::Identifier,
 			Span::new(
@@ -497,6 +604,54 @@ mod tests
 			"a right parenthesis"
 		);
 
+		let token = Token::new(
+			TokenKind::LeftBracket,
+			Span::new(
+				Position {
+					line: 1,
+					column: 1,
+					char_index: 0,
+					offset: 0,
+				},
+				Position {
+					line: 1,
+					column: 2,
+					char_index: 1,
+					offset: 1,
+				},
+			),
+			SmolStr::new("["),
+			SmolStr::new("mabel://stdin"),
+		);
+		assert_eq!(
+			token.description(1, "lowercase", None, true, false),
+			"a left bracket"
+		);
+
+		let token = Token::new(
+			TokenKind::Comma,
+			Span::new(
+				Position {
+					line: 1,
+					column: 1,
+					char_index: 0,
+					offset: 0,
+				},
+				Position {
+					line: 1,
+					column: 2,
+					char_index: 1,
+					offset: 1,
+				},
+			),
+			SmolStr::new(","),
+			SmolStr::new("mabel://stdin"),
+		);
+		assert_eq!(
+			token.description(1, "lowercase", None, true, false),
+			"a comma"
+		);
+
 		let token = Token::new(
 			TokenKind::SingleLineComment,
 			Span::new(